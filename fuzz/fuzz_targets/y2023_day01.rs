@@ -0,0 +1,11 @@
+#![no_main]
+
+use adventofcode::y2023::day01;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = day01::input_generator(input) {
+        let _ = day01::solve_part1(&parsed);
+        let _ = day01::solve_part2(&parsed);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use adventofcode::y2023::day04;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = day04::input_generator(input) {
+        let _ = day04::solve_part1(&parsed);
+        let _ = day04::solve_part2(&parsed);
+    }
+});
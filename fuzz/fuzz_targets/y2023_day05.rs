@@ -0,0 +1,13 @@
+#![no_main]
+
+use adventofcode::y2023::day05;
+use libfuzzer_sys::fuzz_target;
+
+// The brute-force part 2 walks every seed in every range, up to four billion
+// of them, so fuzz the range-splitting solver on any almanac instead.
+fuzz_target!(|input: &str| {
+    if let Ok(almanac) = day05::input_generator(input) {
+        let _ = day05::solve_part1(&almanac);
+        let _ = day05::solve_part2_ranges(&almanac);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use adventofcode::y2023::day06;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(races) = day06::input_generator(input) {
+        let _ = day06::solve_part1(&races);
+        let _ = day06::solve_part2(&races);
+    }
+});
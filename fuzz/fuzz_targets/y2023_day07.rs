@@ -0,0 +1,11 @@
+#![no_main]
+
+use adventofcode::y2023::day07;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = day07::input_generator(input) {
+        let _ = day07::solve_part1(&parsed);
+        let _ = day07::solve_part2(&parsed);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use adventofcode::y2023::day08;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(documents) = day08::input_generator(input) {
+        let _ = day08::solve_part1(&documents);
+        let _ = day08::solve_part2(&documents);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use adventofcode::y2023::day09;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = day09::input_generator(input) {
+        let _ = day09::solve_part1(&parsed);
+        let _ = day09::solve_part2(&parsed);
+    }
+});
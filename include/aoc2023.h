@@ -0,0 +1,64 @@
+#ifndef AOC2023_H
+#define AOC2023_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * What a call returned. Anything but `AOC2023_STATUS_OK` leaves a message
+ * for `aoc2023_last_error`. Later versions may add statuses, so callers
+ * should treat any value they do not know as an error too.
+ */
+typedef enum Aoc2023Status {
+  AOC2023_STATUS_OK = 0,
+  AOC2023_STATUS_INVALID_ARGUMENT = 1,
+  AOC2023_STATUS_UNKNOWN_DAY = 2,
+  AOC2023_STATUS_INVALID_UTF8 = 3,
+  AOC2023_STATUS_PARSE_ERROR = 4,
+  AOC2023_STATUS_SOLVE_ERROR = 5,
+  AOC2023_STATUS_BUFFER_TOO_SMALL = 6,
+  AOC2023_STATUS_PANIC = 7,
+} Aoc2023Status;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Solves `part` (1 or 2) of `day` for the `input_len` bytes of UTF-8 at
+ * `input`, writing the answer to `out` as a NUL-terminated decimal string.
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes, or may be null if
+ * `input_len` is 0. `out` must point to `out_len` writable bytes.
+ */
+enum Aoc2023Status aoc2023_solve(uint32_t day,
+                                 uint32_t part,
+                                 const uint8_t *input,
+                                 size_t input_len,
+                                 uint8_t *out,
+                                 size_t out_len);
+
+/**
+ * Copies the message left by the last failed call on this thread into
+ * `out`, truncating it to fit and NUL-terminating it. Returns the length of
+ * the whole message, not counting the NUL, so a return value of `out_len`
+ * or more means it was truncated. `out` may be null if `out_len` is 0.
+ *
+ * # Safety
+ *
+ * `out` must point to `out_len` writable bytes.
+ */
+size_t aoc2023_last_error(uint8_t *out, size_t out_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC2023_H */
@@ -0,0 +1,409 @@
+use std::io::Read;
+use std::num::NonZero;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use adventofcode::context::Context;
+use adventofcode::error::ParseError;
+use adventofcode::registry;
+use adventofcode::solution::{Answer, Part};
+use clap::Parser;
+use serde::Serialize;
+use tiny_http::{Header, Method, Response, Server};
+
+#[derive(Parser)]
+#[command(
+    name = "aoc-server",
+    about = "Serve the Advent of Code solutions over HTTP"
+)]
+struct Cli {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8023")]
+    addr: String,
+
+    /// Give up on a solve after this many seconds, reporting it as timed out
+    #[arg(long, value_name = "SECONDS", default_value = "30", value_parser = parse_timeout)]
+    timeout: Duration,
+
+    /// Number of requests to handle at once (default: one per CPU)
+    #[arg(long)]
+    workers: Option<NonZero<usize>>,
+}
+
+// Far larger than any puzzle input, which are tens of kilobytes.
+const MAX_BODY: u64 = 1 << 20;
+
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("invalid timeout {s:?}, expected a number of seconds"))
+}
+
+#[derive(Serialize)]
+struct Day {
+    year: u32,
+    day: u32,
+    parts: Vec<PartVariants>,
+}
+
+#[derive(Serialize)]
+struct PartVariants {
+    part: Part,
+    variants: Vec<&'static str>,
+}
+
+// The outcome of one solve. A request that got as far as running a day
+// always gets one of these, whether or not the input parsed.
+#[derive(Serialize)]
+struct Solved {
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: Option<Answer>,
+    parse_ns: u64,
+    solve_ns: Option<u64>,
+    parse_error: Option<ParseError>,
+    solve_error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Failure {
+    error: String,
+}
+
+fn nanos(d: Duration) -> u64 {
+    u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)
+}
+
+fn json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).expect("responses always serialize")
+}
+
+fn failure(status: u16, error: impl Into<String>) -> (u16, String) {
+    (
+        status,
+        json(&Failure {
+            error: error.into(),
+        }),
+    )
+}
+
+fn days() -> Vec<Day> {
+    registry::EVENTS
+        .iter()
+        .flat_map(|event| {
+            event.days.iter().map(|runner| Day {
+                year: event.year,
+                day: runner.day(),
+                parts: Part::ALL
+                    .iter()
+                    .map(|&part| PartVariants {
+                        part,
+                        variants: runner.variants(part),
+                    })
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+fn solve(year: u32, day: u32, part: Part, input: &str, context: &Context) -> (u16, String) {
+    if registry::days(year).is_none() {
+        return failure(404, format!("no days of {year} are solved"));
+    }
+    let Some(runner) = registry::find(year, day) else {
+        return failure(404, format!("{year} day {day} is not solved"));
+    };
+
+    let mut solved = Solved {
+        year,
+        day,
+        part,
+        answer: None,
+        parse_ns: 0,
+        solve_ns: None,
+        parse_error: None,
+        solve_error: None,
+    };
+
+    let start = Instant::now();
+    let parsed = runner.parse_input(input);
+    solved.parse_ns = nanos(start.elapsed());
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            solved.parse_error = Some(e);
+            return (422, json(&solved));
+        }
+    };
+
+    let start = Instant::now();
+    let answer = context.child().run(|| runner.solve_part(part, &parsed));
+    solved.solve_ns = Some(nanos(start.elapsed()));
+
+    match answer {
+        Ok(answer) => {
+            solved.answer = Some(answer);
+            (200, json(&solved))
+        }
+        Err(e) => {
+            solved.solve_error = Some(e.to_string());
+            (422, json(&solved))
+        }
+    }
+}
+
+// Routes a request to its JSON response and status code.
+fn handle(method: &Method, url: &str, body: &str, context: &Context) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or(url);
+    let segments: Vec<_> = path.trim_matches('/').split('/').collect();
+
+    match (method, &segments[..]) {
+        (Method::Get, ["days"]) => (200, json(&days())),
+        (Method::Post, [year, "day", day, "part", part]) => {
+            let Ok(year) = year.parse() else {
+                return failure(404, format!("invalid year {year:?}"));
+            };
+            let Ok(day) = day.parse() else {
+                return failure(404, format!("invalid day {day:?}"));
+            };
+            let Ok(part) = part.parse() else {
+                return failure(404, format!("invalid part {part:?}, expected 1 or 2"));
+            };
+
+            solve(year, day, part, body, context)
+        }
+        (_, ["days"]) | (_, [_, "day", _, "part", _]) => failure(405, "method not allowed"),
+        _ => failure(404, format!("no route for {path}")),
+    }
+}
+
+// A panicking solver is a bug, but it should cost one request rather than a
+// worker.
+fn guarded(f: impl FnOnce() -> (u16, String)) -> (u16, String) {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|_| failure(500, "the solver panicked"))
+}
+
+// Handles requests until the server is dropped. Any number of workers can
+// share one server, each taking the next request as it finishes the last.
+fn serve(server: &Server, context: &Context) {
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let read = request
+            .as_reader()
+            .take(MAX_BODY + 1)
+            .read_to_string(&mut body);
+
+        let (status, json) = match read {
+            Ok(_) if body.len() as u64 > MAX_BODY => {
+                failure(413, format!("the request body is over {MAX_BODY} bytes"))
+            }
+            Ok(_) => guarded(|| handle(request.method(), request.url(), &body, context)),
+            Err(e) => failure(400, format!("reading the request body: {e}")),
+        };
+
+        let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+        let response = Response::from_string(json)
+            .with_status_code(status)
+            .with_header(content_type);
+
+        if let Err(e) = request.respond(response) {
+            eprintln!("responding: {e}");
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let server = match Server::http(&cli.addr) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("error: listening on {}: {e}", cli.addr);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let context = Context::new().with_timeout(Some(cli.timeout));
+    let workers = cli
+        .workers
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZero::get);
+
+    eprintln!("listening on http://{}", server.server_addr());
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| serve(&server, &context));
+        }
+    });
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::{SocketAddr, TcpStream};
+    use std::sync::Arc;
+
+    fn start() -> SocketAddr {
+        start_with(1, Duration::from_secs(30))
+    }
+
+    fn start_with(workers: usize, timeout: Duration) -> SocketAddr {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let context = Context::new().with_timeout(Some(timeout));
+        let addr = server.server_addr();
+
+        for _ in 0..workers {
+            let (server, context) = (Arc::clone(&server), context.clone());
+            thread::spawn(move || serve(&server, &context));
+        }
+        addr
+    }
+
+    // Sends one request over a fresh connection, returning the status and
+    // the parsed JSON body. A server that never answers fails the test
+    // rather than hanging it.
+    fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, serde_json::Value) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(10)))
+            .unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    #[test]
+    fn days() {
+        let addr = start();
+        let (status, days) = request(addr, "GET", "/days", "");
+
+        assert_eq!(status, 200);
+        let solved: usize = registry::EVENTS.iter().map(|event| event.days.len()).sum();
+        assert_eq!(days.as_array().unwrap().len(), solved);
+        assert_eq!(days[7]["year"], 2023);
+        assert_eq!(days[7]["day"], 8);
+        assert_eq!(days[7]["parts"][1]["part"], 2);
+        assert_eq!(
+            days[7]["parts"][1]["variants"],
+            serde_json::json!(["primary", "simulate"])
+        );
+    }
+
+    #[test]
+    fn solves() {
+        let addr = start();
+        let (status, solved) = request(addr, "POST", "/2023/day/9/part/2", "10 13 16 21 30 45\n");
+
+        assert_eq!(status, 200);
+        assert_eq!(solved["answer"], 5);
+        assert_eq!(solved["year"], 2023);
+        assert_eq!(solved["part"], 2);
+        assert!(solved["parse_ns"].is_u64());
+        assert!(solved["solve_ns"].is_u64());
+        assert!(solved["parse_error"].is_null());
+    }
+
+    #[test]
+    fn errors() {
+        let addr = start();
+
+        let (status, solved) = request(addr, "POST", "/2023/day/9/part/1", "1 2\n3 x");
+        assert_eq!(status, 422);
+        assert_eq!(
+            solved["parse_error"],
+            serde_json::json!({"day": 9, "line": 2, "column": 3, "expected": "a number"})
+        );
+        assert!(solved["answer"].is_null());
+
+        let (status, solved) = request(addr, "POST", "/2023/day/8/part/2", "L\n\nAAA = (AAA, AAA)");
+        assert_eq!(status, 422);
+        assert!(solved["solve_error"].is_string());
+
+        for (method, path, expected) in [
+            ("POST", "/2023/day/26/part/1", 404),
+            ("POST", "/2023/day/1/part/3", 404),
+            ("POST", "/2022/day/1/part/1", 404),
+            ("POST", "/last/day/1/part/1", 404),
+            ("GET", "/2023/day/1/part/1", 405),
+            ("GET", "/nowhere", 404),
+        ] {
+            let (status, failure) = request(addr, method, path, "");
+            assert_eq!(status, expected, "{method} {path}");
+            assert!(failure["error"].is_string(), "{method} {path}");
+        }
+    }
+
+    // Every seed from 0 to four billion through the brute-force part 2.
+    const SLOW: &str = "seeds: 0 4000000000\n\nseed-to-soil map:\n50 98 2";
+
+    #[test]
+    fn times_out() {
+        let addr = start_with(1, Duration::ZERO);
+        let (status, solved) = request(addr, "POST", "/2023/day/5/part/2", SLOW);
+
+        assert_eq!(status, 422);
+        assert_eq!(solved["solve_error"], "timed out");
+    }
+
+    #[test]
+    fn workers() {
+        let addr = start_with(2, Duration::from_secs(30));
+
+        // A worker only sends `100 Continue` once it has taken a request and
+        // starts reading its body, and then waits for that body; the other
+        // worker must answer in the meantime.
+        let input = "0 3 6 9 12 15";
+        let mut held = TcpStream::connect(addr).unwrap();
+        write!(
+            held,
+            "POST /2023/day/9/part/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\
+             Expect: 100-continue\r\nConnection: close\r\n\r\n",
+            input.len()
+        )
+        .unwrap();
+        let mut line = String::new();
+        BufReader::new(&held).read_line(&mut line).unwrap();
+        assert!(line.starts_with("HTTP/1.1 100"), "{line}");
+
+        let (status, _) = request(addr, "GET", "/days", "");
+        assert_eq!(status, 200);
+
+        held.write_all(input.as_bytes()).unwrap();
+        let mut response = String::new();
+        held.read_to_string(&mut response).unwrap();
+        assert!(response.contains(r#""answer":18"#), "{response}");
+    }
+
+    #[test]
+    fn limits() {
+        let addr = start();
+        let body = "1 ".repeat(MAX_BODY as usize / 2 + 1);
+        let (status, failure) = request(addr, "POST", "/2023/day/9/part/1", &body);
+
+        assert_eq!(status, 413);
+        assert!(failure["error"].is_string());
+
+        let (status, failure) = guarded(|| panic!("a solver bug"));
+        assert_eq!(status, 500);
+        assert!(failure.contains("panicked"));
+    }
+}
@@ -0,0 +1,189 @@
+use std::time::{Duration, Instant};
+
+use adventofcode::context::Context;
+use adventofcode::solution::{Answer, Part, Runner};
+use rayon::prelude::*;
+
+use crate::bench::format_ns;
+use crate::monitor;
+
+pub struct Row {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub parse: Option<Duration>,
+    pub solve: Option<Duration>,
+    pub status: String,
+}
+
+impl Row {
+    fn failed(day: u32, part: Part, parse: Option<Duration>, status: String) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            parse,
+            solve: None,
+            status,
+        }
+    }
+
+    pub fn ok(&self) -> bool {
+        self.answer.is_some()
+    }
+}
+
+// Parses each day once and solves both of its parts on the thread pool,
+// every day at the same time, each part under its own child of `context`.
+// Rows come back in day and part order.
+pub fn run(days: Vec<(&dyn Runner, Result<String, String>)>, context: &Context) -> Vec<Row> {
+    let days: Vec<_> = days
+        .into_iter()
+        .map(|(runner, input)| (runner, input, Part::ALL.map(|_| context.child())))
+        .collect();
+    let solves: Vec<_> = days
+        .iter()
+        .flat_map(|(runner, _, contexts)| {
+            Part::ALL.iter().zip(contexts).map(|(part, context)| {
+                (format!("day {} part {part}", runner.day()), context.clone())
+            })
+        })
+        .collect();
+
+    monitor::watch(&solves, || {
+        days.into_par_iter()
+            .flat_map_iter(|(runner, input, contexts)| -> Vec<Row> {
+                let day = runner.day();
+
+                let input = match input {
+                    Ok(input) => input,
+                    Err(e) => {
+                        return Part::ALL
+                            .map(|part| Row::failed(day, part, None, e.clone()))
+                            .into();
+                    }
+                };
+
+                let start = Instant::now();
+                let parsed = runner.parse_input(&input);
+                let parse = Some(start.elapsed());
+
+                let parsed = match parsed {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        return Part::ALL
+                            .map(|part| Row::failed(day, part, parse, e.to_string()))
+                            .into();
+                    }
+                };
+
+                Part::ALL
+                    .into_par_iter()
+                    .zip(contexts)
+                    .map(|(part, context)| {
+                        let start = Instant::now();
+                        let answer = context.run(|| runner.solve_part(part, &parsed));
+                        let solve = Some(start.elapsed());
+
+                        match answer {
+                            Ok(answer) => Row {
+                                day,
+                                part,
+                                answer: Some(answer),
+                                parse,
+                                solve,
+                                status: "ok".to_string(),
+                            },
+                            Err(e) => Row {
+                                solve,
+                                ..Row::failed(day, part, parse, e.to_string())
+                            },
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    })
+}
+
+pub fn print(rows: &[Row], wall: Duration) {
+    let time = |d: Option<Duration>| {
+        d.map(|d| format_ns(u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)))
+            .unwrap_or_default()
+    };
+
+    println!(
+        "{:>3}  {:>4}  {:>16}  {:>9}  {:>9}  Status",
+        "Day", "Part", "Answer", "parse", "solve"
+    );
+
+    for row in rows {
+        println!(
+            "{:>3}  {:>4}  {:>16}  {:>9}  {:>9}  {}",
+            row.day,
+            row.part,
+            row.answer.map(|a| a.to_string()).unwrap_or_default(),
+            time(row.parse),
+            time(row.solve),
+            row.status
+        );
+    }
+
+    let busy: Duration = rows.iter().filter_map(|row| row.solve).sum();
+    println!(
+        "Total: {} wall clock, {} solving",
+        time(Some(wall)),
+        time(Some(busy))
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode::registry;
+
+    #[test]
+    fn rows_in_order() {
+        let days = vec![
+            (
+                registry::find(2023, 9).unwrap(),
+                Ok("0 3 6 9 12 15".to_string()),
+            ),
+            (registry::find(2023, 6).unwrap(), Err("missing".to_string())),
+            (
+                registry::find(2023, 4).unwrap(),
+                Ok("Card 1: x".to_string()),
+            ),
+        ];
+
+        let rows = run(days, &Context::new());
+        let summary: Vec<_> = rows
+            .iter()
+            .map(|row| (row.day, row.part, row.answer, row.ok()))
+            .collect();
+
+        assert_eq!(
+            summary,
+            [
+                (9, Part::One, Some(Answer(18)), true),
+                (9, Part::Two, Some(Answer(-3)), true),
+                (6, Part::One, None, false),
+                (6, Part::Two, None, false),
+                (4, Part::One, None, false),
+                (4, Part::Two, None, false),
+            ]
+        );
+        assert_eq!(rows[2].status, "missing");
+    }
+
+    #[test]
+    fn timed_out() {
+        let almanac = "seeds: 0 4000000000\n\nseed-to-soil map:\n1 0 10";
+        let days = vec![(registry::find(2023, 5).unwrap(), Ok(almanac.to_string()))];
+
+        let rows = run(days, &Context::new().with_timeout(Some(Duration::ZERO)));
+        let statuses: Vec<_> = rows.iter().map(|row| row.status.as_str()).collect();
+
+        assert_eq!(statuses, ["ok", "timed out"]);
+    }
+}
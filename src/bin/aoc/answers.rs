@@ -0,0 +1,210 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use adventofcode::solution::{Answer, Part};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Deserialize, Serialize)]
+pub struct AnswerStore {
+    #[serde(default, rename = "answer")]
+    answers: Vec<Entry>,
+    // Parts of days added by `new-day` that have no answer recorded yet.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pending: Vec<Pending>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+struct Entry {
+    // Stores written before other years were solved only hold 2023 answers.
+    #[serde(default = "first_year")]
+    year: u32,
+    day: u32,
+    part: u32,
+    input: String,
+    expected: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+struct Pending {
+    year: u32,
+    day: u32,
+    part: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Mismatch { expected: Answer },
+    New,
+}
+
+fn first_year() -> u32 {
+    2023
+}
+
+// FNV-1a, chosen because it is stable across Rust releases and platforms,
+// unlike `DefaultHasher`.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+impl AnswerStore {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s).map_err(|e| format!("parsing {}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("reading {}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&mut self, path: &Path) -> Result<(), String> {
+        self.answers.sort_by(|a, b| {
+            (a.year, a.day, a.part, &a.input).cmp(&(b.year, b.day, b.part, &b.input))
+        });
+        self.pending.sort();
+
+        let s = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, s).map_err(|e| format!("writing {}: {e}", path.display()))
+    }
+
+    pub fn check(&self, year: u32, day: u32, part: Part, hash: &str, answer: Answer) -> Outcome {
+        let part = u32::from(part);
+
+        match self
+            .answers
+            .iter()
+            .find(|e| e.year == year && e.day == day && e.part == part && e.input == hash)
+        {
+            Some(e) if e.expected == answer.0 => Outcome::Correct,
+            Some(e) => Outcome::Mismatch {
+                expected: Answer(e.expected),
+            },
+            None => Outcome::New,
+        }
+    }
+
+    pub fn record(&mut self, year: u32, day: u32, part: Part, hash: &str, answer: Answer) {
+        let part = u32::from(part);
+
+        self.answers
+            .retain(|e| !(e.year == year && e.day == day && e.part == part && e.input == hash));
+        self.answers.push(Entry {
+            year,
+            day,
+            part,
+            input: hash.to_string(),
+            expected: answer.0,
+        });
+        self.pending
+            .retain(|p| !(p.year == year && p.day == day && p.part == part));
+    }
+
+    // Marks both parts of a new day as waiting for their first answer.
+    pub fn expect(&mut self, year: u32, day: u32) {
+        for part in Part::ALL.map(u32::from) {
+            let pending = Pending { year, day, part };
+            let recorded = self
+                .answers
+                .iter()
+                .any(|e| e.year == year && e.day == day && e.part == part);
+
+            if !recorded && !self.pending.contains(&pending) {
+                self.pending.push(pending);
+            }
+        }
+    }
+
+    // Every day and part of `year` still waiting for its first answer.
+    pub fn pending(&self, year: u32) -> Vec<(u32, u32)> {
+        self.pending
+            .iter()
+            .filter(|p| p.year == year)
+            .map(|p| (p.day, p.part))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_and_record() {
+        let mut store = AnswerStore::default();
+        let hash = input_hash("0 3 6 9 12 15");
+
+        assert_eq!(
+            store.check(2023, 9, Part::One, &hash, Answer(18)),
+            Outcome::New
+        );
+
+        store.record(2023, 9, Part::One, &hash, Answer(18));
+        assert_eq!(
+            store.check(2023, 9, Part::One, &hash, Answer(18)),
+            Outcome::Correct
+        );
+        assert_eq!(
+            store.check(2023, 9, Part::One, &hash, Answer(17)),
+            Outcome::Mismatch {
+                expected: Answer(18)
+            }
+        );
+        assert_eq!(
+            store.check(2023, 9, Part::Two, &hash, Answer(18)),
+            Outcome::New
+        );
+        assert_eq!(
+            store.check(2024, 9, Part::One, &hash, Answer(18)),
+            Outcome::New
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut store = AnswerStore::default();
+        store.record(2023, 5, Part::Two, &input_hash("seeds: 1"), Answer(46));
+
+        let parsed: AnswerStore = toml::from_str(&toml::to_string(&store).unwrap()).unwrap();
+        assert_eq!(parsed.answers, store.answers);
+    }
+
+    #[test]
+    fn pending_days() {
+        let mut store = AnswerStore::default();
+        store.expect(2024, 1);
+        store.expect(2024, 1);
+        assert_eq!(store.pending(2024), [(1, 1), (1, 2)]);
+        assert_eq!(store.pending(2023), []);
+
+        let saved = toml::to_string(&store).unwrap();
+        assert!(
+            saved.contains("[[pending]]\nyear = 2024\nday = 1\npart = 2\n"),
+            "{saved}"
+        );
+
+        store.record(2024, 1, Part::One, &input_hash("1"), Answer(7));
+        assert_eq!(store.pending(2024), [(1, 2)]);
+        store.expect(2024, 1);
+        assert_eq!(store.pending(2024), [(1, 2)]);
+
+        let parsed: AnswerStore = toml::from_str(&toml::to_string(&store).unwrap()).unwrap();
+        assert_eq!(parsed.pending, store.pending);
+    }
+
+    #[test]
+    fn entries_without_a_year() {
+        let store: AnswerStore =
+            toml::from_str("[[answer]]\nday = 9\npart = 1\ninput = \"abc\"\nexpected = 18\n")
+                .unwrap();
+
+        assert_eq!(
+            store.check(2023, 9, Part::One, "abc", Answer(18)),
+            Outcome::Correct
+        );
+    }
+}
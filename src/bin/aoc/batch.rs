@@ -0,0 +1,324 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use adventofcode::context::Context;
+use adventofcode::registry;
+use adventofcode::solution::{Answer, Part, Runner};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::bench::format_ns;
+
+// One person's input for one day.
+#[derive(Debug, Serialize)]
+pub struct Entry {
+    pub person: String,
+    pub day: u32,
+    pub parse_ns: Option<u64>,
+    pub parts: Vec<Solved>,
+    pub error: Option<String>,
+    // The days whose inputs this one is laid out like, when its own day is not
+    // among them; it is not solved then.
+    pub looks_like: Vec<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub solve_ns: u64,
+    pub error: Option<String>,
+}
+
+fn nanos(d: Duration) -> u64 {
+    u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)
+}
+
+// Finds `<dir>/day<N>/<person>.txt` for every solved day of `year`, in day
+// and then person order.
+pub fn find(year: u32, dir: &Path) -> Result<Vec<(u32, String, PathBuf)>, String> {
+    let read_dir =
+        |dir: &Path| fs::read_dir(dir).map_err(|e| format!("reading {}: {e}", dir.display()));
+    let mut found = Vec::new();
+
+    for day_dir in read_dir(dir)? {
+        let day_dir = day_dir.map_err(|e| e.to_string())?.path();
+        let day = day_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse().ok())
+            .filter(|&day| registry::find(year, day).is_some());
+        let Some(day) = day.filter(|_| day_dir.is_dir()) else {
+            continue;
+        };
+
+        for file in read_dir(&day_dir)? {
+            let path = file.map_err(|e| e.to_string())?.path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                let person = path.file_stem().unwrap().to_string_lossy().into_owned();
+                found.push((day, person, path));
+            }
+        }
+    }
+
+    found.sort();
+    Ok(found)
+}
+
+fn solve(
+    year: u32,
+    runner: &dyn Runner,
+    person: String,
+    input: Result<String, String>,
+    context: &Context,
+) -> Entry {
+    let mut entry = Entry {
+        person,
+        day: runner.day(),
+        parse_ns: None,
+        parts: Vec::new(),
+        error: None,
+        looks_like: Vec::new(),
+    };
+
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            entry.error = Some(e);
+            return entry;
+        }
+    };
+
+    // Checked before parsing, since a lenient parser can accept another
+    // day's input.
+    let looks_like = registry::looks_like(year, &input);
+    if !looks_like.is_empty() && !looks_like.contains(&entry.day) {
+        entry.looks_like = looks_like;
+        return entry;
+    }
+
+    let start = Instant::now();
+    let parsed = runner.parse_input(&input);
+    entry.parse_ns = Some(nanos(start.elapsed()));
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            entry.error = Some(e.to_string());
+            return entry;
+        }
+    };
+
+    entry.parts = Part::ALL
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = context.child().run(|| runner.solve_part(part, &parsed));
+
+            Solved {
+                part,
+                solve_ns: nanos(start.elapsed()),
+                error: answer.as_ref().err().map(ToString::to_string),
+                answer: answer.ok(),
+            }
+        })
+        .collect();
+
+    entry
+}
+
+// Solves every input on the thread pool, keeping the order they came in.
+// Each part gets its own child of `context`.
+pub fn run(
+    year: u32,
+    inputs: Vec<(&dyn Runner, String, Result<String, String>)>,
+    context: &Context,
+) -> Vec<Entry> {
+    inputs
+        .into_par_iter()
+        .map(|(runner, person, input)| solve(year, runner, person, input, context))
+        .collect()
+}
+
+impl Entry {
+    pub fn failed(&self) -> bool {
+        self.error.is_some()
+            || !self.looks_like.is_empty()
+            || self.parts.iter().any(|p| p.error.is_some())
+    }
+
+    fn cell(&self, part: Part) -> String {
+        match self.parts.iter().find(|p| p.part == part) {
+            Some(Solved {
+                answer: Some(answer),
+                ..
+            }) => answer.to_string(),
+            Some(_) => "error".to_string(),
+            None if !self.looks_like.is_empty() => "wrong day".to_string(),
+            None => "error".to_string(),
+        }
+    }
+
+    fn time(&self) -> u64 {
+        self.parse_ns.unwrap_or(0) + self.parts.iter().map(|p| p.solve_ns).sum::<u64>()
+    }
+}
+
+// A row per person and a column per day and part, followed by every input
+// that failed and why.
+pub fn print(entries: &[Entry]) {
+    let days: BTreeSet<u32> = entries.iter().map(|e| e.day).collect();
+    let people: BTreeSet<&str> = entries.iter().map(|e| e.person.as_str()).collect();
+
+    let columns: Vec<(u32, Part)> = days
+        .iter()
+        .flat_map(|&day| Part::ALL.map(|part| (day, part)))
+        .collect();
+    let rows: Vec<(&str, Vec<String>, u64)> = people
+        .iter()
+        .map(|&person| {
+            let mine: Vec<_> = entries.iter().filter(|e| e.person == person).collect();
+            let cells = columns
+                .iter()
+                .map(|&(day, part)| {
+                    mine.iter()
+                        .find(|e| e.day == day)
+                        .map_or_else(|| "-".to_string(), |e| e.cell(part))
+                })
+                .collect();
+            (person, cells, mine.iter().map(|e| e.time()).sum())
+        })
+        .collect();
+
+    let name_width = people.iter().map(|p| p.len()).max().unwrap_or(0).max(6);
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, _)| {
+            rows.iter()
+                .map(|(_, cells, _)| cells[i].len())
+                .max()
+                .unwrap_or(0)
+                .max(5)
+        })
+        .collect();
+
+    let mut header = format!("{:<name_width$}", "Person");
+    for ((day, part), width) in columns.iter().zip(&widths) {
+        header += &format!("  {:>width$}", format!("{day}.{part}"));
+    }
+    println!("{header}  {:>9}", "time");
+
+    for (person, cells, time) in &rows {
+        let mut line = format!("{person:<name_width$}");
+        for (cell, width) in cells.iter().zip(&widths) {
+            line += &format!("  {cell:>width$}");
+        }
+        println!("{line}  {:>9}", format_ns(*time));
+    }
+
+    for entry in entries.iter().filter(|e| e.failed()) {
+        let errors = entry
+            .error
+            .iter()
+            .chain(entry.parts.iter().filter_map(|p| p.error.as_ref()));
+        for error in errors {
+            println!("{} day {}: {error}", entry.person, entry.day);
+        }
+        if !entry.looks_like.is_empty() {
+            let days: Vec<_> = entry.looks_like.iter().map(u32::to_string).collect();
+            println!(
+                "{} day {}: input looks like day {} instead",
+                entry.person,
+                entry.day,
+                days.join(" or ")
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(text: &str) -> Result<String, String> {
+        Ok(text.to_string())
+    }
+
+    #[test]
+    fn wrong_day() {
+        let day1 = registry::find(2023, 1).unwrap();
+        let day6 = registry::find(2023, 6).unwrap();
+        let day9 = registry::find(2023, 9).unwrap();
+
+        let entries = run(
+            2023,
+            vec![
+                (day9, "alice".to_string(), input("0 3 6 9 12 15")),
+                (day6, "bob".to_string(), input("0 3 6 9 12 15")),
+                (day9, "bob".to_string(), input("Time: 7\nDistance: 9")),
+                (day1, "carol".to_string(), input("0 3 6 9 12 15")),
+                (day9, "carol".to_string(), Err("unreadable".to_string())),
+            ],
+            &Context::new(),
+        );
+
+        let summary: Vec<_> = entries
+            .iter()
+            .map(|e| (e.person.as_str(), e.day, e.cell(Part::One), e.failed()))
+            .collect();
+
+        assert_eq!(
+            summary,
+            [
+                ("alice", 9, "18".to_string(), false),
+                ("bob", 6, "wrong day".to_string(), true),
+                ("bob", 9, "wrong day".to_string(), true),
+                ("carol", 1, "wrong day".to_string(), true),
+                ("carol", 9, "error".to_string(), true),
+            ]
+        );
+
+        // Other days may share a layout, but the right one is always listed
+        // and the day it was filed under never is.
+        for (entry, right) in entries[1..4].iter().zip([9, 6, 9]) {
+            assert!(entry.looks_like.contains(&right), "{entry:?}");
+            assert!(!entry.looks_like.contains(&entry.day), "{entry:?}");
+        }
+        assert!(entries[0].looks_like.is_empty());
+    }
+
+    #[test]
+    fn finds_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        for (path, text) in [
+            ("day9/bob.txt", "1 2 3"),
+            ("day9/alice.txt", "1 2 3"),
+            ("day09/carol.txt", "1 2 3"),
+            ("day9/notes.md", ""),
+            ("day26/alice.txt", ""),
+            ("misc/alice.txt", ""),
+        ] {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+
+        let found: Vec<_> = find(2023, &dir)
+            .unwrap()
+            .into_iter()
+            .map(|(day, person, _)| (day, person))
+            .collect();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            found,
+            [
+                (9, "alice".to_string()),
+                (9, "bob".to_string()),
+                (9, "carol".to_string())
+            ]
+        );
+    }
+}
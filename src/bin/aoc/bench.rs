@@ -0,0 +1,334 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use adventofcode::context::Context;
+use adventofcode::error::{Error, SolveError};
+use adventofcode::profile::{self, Usage};
+use adventofcode::solution::{Part, Runner};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u32,
+    pub phase: Phase,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub min_ns: u64,
+    // Only measured with `--alloc`, and missing from older reports.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alloc: Option<Usage>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Report {
+    pub revision: String,
+    pub iterations: usize,
+    pub measurements: Vec<Measurement>,
+}
+
+pub struct Comparison<'a> {
+    pub baseline: Option<&'a Measurement>,
+    pub regressed: bool,
+    pub alloc_regressed: bool,
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Phase::Part1,
+            Part::Two => Phase::Part2,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+impl Measurement {
+    fn new(day: u32, phase: Phase, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let nanos = |d: Duration| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX);
+        let percentile = |p: usize| nanos(samples[(samples.len() - 1) * p / 100]);
+
+        Self {
+            day,
+            phase,
+            median_ns: percentile(50),
+            p95_ns: percentile(95),
+            min_ns: nanos(samples[0]),
+            alloc: None,
+        }
+    }
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let s = fs::read_to_string(path).map_err(|e| format!("reading {}: {e}", path.display()))?;
+        serde_json::from_str(&s).map_err(|e| format!("parsing {}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("creating {}: {e}", dir.display()))?;
+        }
+
+        let s = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, s).map_err(|e| format!("writing {}: {e}", path.display()))
+    }
+
+    pub fn compare<'a>(&'a self, baseline: &'a Report, threshold: f64) -> Vec<Comparison<'a>> {
+        self.measurements
+            .iter()
+            .map(|current| {
+                let baseline = baseline
+                    .measurements
+                    .iter()
+                    .find(|m| m.day == current.day && m.phase == current.phase);
+                let regressed = baseline.is_some_and(|b| change(b, current) > threshold);
+                let alloc_regressed = baseline
+                    .and_then(|b| alloc_change(b, current))
+                    .is_some_and(|change| change > threshold);
+
+                Comparison {
+                    baseline,
+                    regressed,
+                    alloc_regressed,
+                }
+            })
+            .collect()
+    }
+}
+
+// Percentage change in median time from `baseline` to `current`.
+pub fn change(baseline: &Measurement, current: &Measurement) -> f64 {
+    (current.median_ns as f64 - baseline.median_ns as f64) / baseline.median_ns.max(1) as f64
+        * 100.0
+}
+
+// Percentage change in bytes allocated from `baseline` to `current`, if both
+// were measured.
+pub fn alloc_change(baseline: &Measurement, current: &Measurement) -> Option<f64> {
+    let (baseline, current) = (baseline.alloc?, current.alloc?);
+
+    Some((current.bytes as f64 - baseline.bytes as f64) / baseline.bytes.max(1) as f64 * 100.0)
+}
+
+// How long one solve may take when `--timeout` is not given, so that a
+// brute-force solver such as day 5 part 2 does not hold up the whole run.
+pub const TIMEOUT: Duration = Duration::from_secs(10);
+
+// With `alloc`, each phase is run once more outside the timed loop to count
+// its allocations; that needs the counting allocator installed. Every solve
+// runs under `context`, and a part that times out is left out of the
+// result instead of failing the run.
+pub fn measure(
+    runner: &dyn Runner,
+    input: &str,
+    iterations: usize,
+    alloc: bool,
+    context: &Context,
+) -> Result<Vec<Measurement>, Error> {
+    let day = runner.day();
+    let iterations = iterations.max(1);
+
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        runner.parse_input(input)?;
+        samples.push(start.elapsed());
+    }
+
+    let mut measurements = vec![Measurement::new(day, Phase::Parse, samples)];
+    if alloc {
+        let (parsed, usage) = profile::measure(|| runner.parse_input(input));
+        parsed?;
+        measurements[0].alloc = Some(usage);
+    }
+
+    'parts: for part in Part::ALL {
+        let mut samples = Vec::with_capacity(iterations);
+
+        // Parse afresh each time so a solver can never observe state left
+        // behind by an earlier iteration.
+        for _ in 0..iterations {
+            let parsed = runner.parse_input(input)?;
+            let start = Instant::now();
+            match context.run(|| runner.solve_part(part, &parsed)) {
+                Err(SolveError::TimedOut) => continue 'parts,
+                answer => answer?,
+            };
+            samples.push(start.elapsed());
+        }
+
+        let mut measurement = Measurement::new(day, part.into(), samples);
+        if alloc {
+            let parsed = runner.parse_input(input)?;
+            let (answer, usage) =
+                profile::measure(|| context.run(|| runner.solve_part(part, &parsed)));
+            match answer {
+                Err(SolveError::TimedOut) => continue 'parts,
+                answer => answer?,
+            };
+            measurement.alloc = Some(usage);
+        }
+        measurements.push(measurement);
+    }
+
+    Ok(measurements)
+}
+
+pub fn git_revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+    };
+
+    match (
+        git(&["rev-parse", "--short", "HEAD"]),
+        git(&["status", "--porcelain"]),
+    ) {
+        (Some(rev), Some(status)) if !status.is_empty() => format!("{rev}-dirty"),
+        (Some(rev), _) => rev,
+        (None, _) => "unknown".to_string(),
+    }
+}
+
+pub fn format_ns(ns: u64) -> String {
+    match ns {
+        0..=9_999 => format!("{ns}ns"),
+        10_000..=9_999_999 => format!("{:.1}µs", ns as f64 / 1e3),
+        10_000_000..=9_999_999_999 => format!("{:.1}ms", ns as f64 / 1e6),
+        _ => format!("{:.2}s", ns as f64 / 1e9),
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..=9_999 => format!("{bytes}B"),
+        10_000..=9_999_999 => format!("{:.1}KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1}MiB", bytes as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(phase: Phase, median_ns: u64) -> Measurement {
+        Measurement {
+            day: 5,
+            phase,
+            median_ns,
+            p95_ns: median_ns,
+            min_ns: median_ns,
+            alloc: None,
+        }
+    }
+
+    #[test]
+    fn statistics() {
+        let samples = (1..=100).rev().map(Duration::from_nanos).collect();
+        let m = Measurement::new(1, Phase::Parse, samples);
+
+        assert_eq!((m.min_ns, m.median_ns, m.p95_ns), (1, 50, 95));
+    }
+
+    #[test]
+    fn regressions() {
+        let report = |measurements| Report {
+            revision: String::new(),
+            iterations: 1,
+            measurements,
+        };
+        let baseline = report(vec![
+            measurement(Phase::Parse, 1000),
+            measurement(Phase::Part1, 1000),
+        ]);
+        let current = report(vec![
+            measurement(Phase::Parse, 1050),
+            measurement(Phase::Part1, 1200),
+            measurement(Phase::Part2, 1000),
+        ]);
+
+        let regressed: Vec<_> = current
+            .compare(&baseline, 10.0)
+            .iter()
+            .map(|c| (c.baseline.is_some(), c.regressed))
+            .collect();
+
+        assert_eq!(regressed, [(true, false), (true, true), (false, false)]);
+    }
+
+    #[test]
+    fn alloc_regressions() {
+        let with_bytes = |bytes| Measurement {
+            alloc: Some(Usage {
+                allocations: 1,
+                bytes,
+                peak_bytes: bytes,
+            }),
+            ..measurement(Phase::Parse, 1000)
+        };
+        let report = |measurements| Report {
+            revision: String::new(),
+            iterations: 1,
+            measurements,
+        };
+
+        let baseline = report(vec![with_bytes(1000)]);
+        let compare = |current: Measurement| {
+            let current = report(vec![current]);
+            let c = &current.compare(&baseline, 10.0)[0];
+            (c.regressed, c.alloc_regressed)
+        };
+
+        assert_eq!(compare(with_bytes(1050)), (false, false));
+        assert_eq!(compare(with_bytes(2000)), (false, true));
+        assert_eq!(compare(measurement(Phase::Parse, 1000)), (false, false));
+
+        // Reports saved before allocations were measured still load.
+        let old = r#"{"day":1,"phase":"parse","median_ns":1,"p95_ns":1,"min_ns":1}"#;
+        assert_eq!(
+            serde_json::from_str::<Measurement>(old).unwrap().alloc,
+            None
+        );
+    }
+
+    #[test]
+    fn skips_parts_that_time_out() {
+        let runner = adventofcode::registry::find(2023, 5).unwrap();
+        let input = "seeds: 0 4000000000\n\nseed-to-soil map:\n50 98 2";
+        let context = Context::new().with_timeout(Some(Duration::ZERO));
+
+        let phases: Vec<_> = measure(runner, input, 3, false, &context)
+            .unwrap()
+            .iter()
+            .map(|m| m.phase)
+            .collect();
+
+        assert_eq!(phases, [Phase::Parse, Phase::Part1]);
+    }
+}
@@ -0,0 +1,842 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use adventofcode::context::Context;
+use adventofcode::diff::{self, Disagreement};
+use adventofcode::gen;
+use adventofcode::normalize::{self, Options};
+use adventofcode::registry;
+use adventofcode::solution::{Part, Runner};
+use adventofcode::svg;
+use clap::{Parser, Subcommand};
+use tracing::Level;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::fmt;
+use tracing_subscriber::prelude::*;
+
+use answers::{input_hash, AnswerStore, Outcome};
+use bench::Report;
+
+#[cfg(feature = "alloc-profile")]
+#[global_allocator]
+static ALLOCATOR: adventofcode::profile::Counting = adventofcode::profile::Counting;
+
+mod all;
+mod answers;
+mod batch;
+mod bench;
+mod monitor;
+mod repl;
+mod scaffold;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    /// Year of the event to work on
+    #[arg(long, global = true, default_value_t = registry::latest_year())]
+    year: u32,
+
+    /// Directory holding puzzle inputs as `<year>/day<N>.txt`
+    #[arg(long, global = true, default_value = "input")]
+    input_dir: PathBuf,
+
+    /// Replace tabs in inputs with spaces
+    #[arg(long, global = true)]
+    tabs_to_spaces: bool,
+
+    /// Report how each input was normalized before parsing
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
+    /// Log what a day's solver does, as DAY or DAY=LEVEL (default debug; info for counts only, trace for more)
+    #[arg(long, global = true, value_name = "DAY[=LEVEL]")]
+    explain: Vec<Explain>,
+
+    /// Give up on a part after this many seconds, reporting it as timed out (run, all and batch; bench skips it, after 10 seconds by default)
+    #[arg(long, global = true, value_name = "SECONDS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, optionally only one part
+    Run {
+        day: u32,
+        part: Option<Part>,
+
+        /// Read the puzzle input from this file instead of the input directory
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Solve both parts of every day of the year in parallel and print a timing table
+    All,
+    /// Solve everyone's inputs, laid out as `<dir>/day<N>/<person>.txt`, and print a person by day table
+    Batch {
+        dir: PathBuf,
+
+        /// Print every result with its timings as JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check every day's answers on the local inputs against the answer store
+    Verify {
+        /// Answer store mapping year, day, part and input hash to the expected answer
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+
+        /// Add answers for inputs the store has not seen before
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time the parse and solve phases of each day
+    Bench {
+        /// Only benchmark this day
+        day: Option<u32>,
+
+        /// Number of timed runs of each phase
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+
+        /// Directory holding one JSON report per year and git revision
+        #[arg(long, default_value = ".bench")]
+        history_dir: PathBuf,
+
+        /// Revision whose saved report to compare against
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Slowdown of the median, in percent, that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Don't save this run's report to the history directory
+        #[arg(long)]
+        no_save: bool,
+
+        /// Also count allocations, bytes allocated and peak live bytes of each phase
+        #[arg(long)]
+        alloc: bool,
+    },
+    /// Generate a random input for a day, printing its expected answers
+    Gen {
+        day: u32,
+
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Number of records (lines, games, hands, ghosts...) to generate
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+
+        /// Write the input to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Run every solver variant on the same inputs and report disagreements
+    Diff {
+        /// Only check this day
+        day: Option<u32>,
+
+        /// Check this file instead of the input directory (requires a day)
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+
+        /// Also check inputs generated from this many seeds, starting at 0
+        #[arg(long, default_value_t = 0)]
+        generated: u64,
+
+        /// Size of each generated input
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+    /// Draw a day's input as an SVG picture (2023 days 3, 5, 8 and 9)
+    Render {
+        day: u32,
+
+        /// Read the puzzle input from this file instead of the input directory
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Write the picture to this file instead of `day<N>.svg`
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Load a day's input once and explore it interactively (2023 days 2, 3, 5, 7, 8 and 9)
+    Repl {
+        day: u32,
+
+        /// Read the puzzle input from this file instead of the input directory
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Print a day's parsed input as JSON
+    Dump {
+        day: u32,
+
+        /// Read the puzzle input from this file instead of the input directory
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Add a skeleton for a new day and register it with the library and fuzz targets
+    NewDay {
+        day: u32,
+
+        /// Root of the crate to add the day to, creating the year's module if it is the first day (default: the nearest directory at or above the current one holding the crate's Cargo.toml)
+        #[arg(long)]
+        root: Option<PathBuf>,
+
+        /// Answer store to add the day to, as waiting for its first answers (default: answers.toml in the crate root)
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+}
+
+struct DiffArgs {
+    day: Option<u32>,
+    input: Option<PathBuf>,
+    generated: u64,
+    size: usize,
+}
+
+#[derive(Clone)]
+struct Explain {
+    day: u32,
+    level: Level,
+}
+
+impl FromStr for Explain {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (day, level) = s.split_once('=').unwrap_or((s, "debug"));
+
+        // The solvers only log at these levels.
+        Ok(Self {
+            day: day.parse().map_err(|_| format!("invalid day {day:?}"))?,
+            level: match level {
+                "info" => Level::INFO,
+                "debug" => Level::DEBUG,
+                "trace" => Level::TRACE,
+                _ => {
+                    return Err(format!(
+                        "invalid level {level:?}, expected info, debug or trace"
+                    ))
+                }
+            },
+        })
+    }
+}
+
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("invalid timeout {s:?}, expected a number of seconds"))
+}
+
+struct BenchArgs {
+    day: Option<u32>,
+    iterations: usize,
+    history_dir: PathBuf,
+    baseline: Option<String>,
+    threshold: f64,
+    save: bool,
+    alloc: bool,
+    timeout: Duration,
+}
+
+struct Inputs {
+    dir: PathBuf,
+    options: Options,
+    verbose: bool,
+}
+
+impl Inputs {
+    fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{day}.txt"))
+    }
+
+    fn read(&self, path: &Path) -> Result<String, String> {
+        let raw =
+            fs::read_to_string(path).map_err(|e| format!("reading {}: {e}", path.display()))?;
+        let (input, changes) = normalize::normalize(&raw, self.options);
+
+        if self.verbose && !changes.is_empty() {
+            eprintln!("{}: {changes}", path.display());
+        }
+
+        Ok(input)
+    }
+}
+
+fn run_day(
+    inputs: &Inputs,
+    context: &Context,
+    runner: &dyn Runner,
+    parts: &[Part],
+    path: &Path,
+) -> Result<(), String> {
+    let day = runner.day();
+    let input = inputs.read(path)?;
+    let parsed = runner.parse_input(&input).map_err(|e| e.to_string())?;
+
+    for &part in parts {
+        let solve = context.child();
+        let watched = [(format!("day {day} part {part}"), solve.clone())];
+        let answer = monitor::watch(&watched, || solve.run(|| runner.solve_part(part, &parsed)))
+            .map_err(|e| format!("day {day} part {part}: {e}"))?;
+        println!("Day {day} - Part {part}: {answer}");
+    }
+
+    Ok(())
+}
+
+fn batch(
+    inputs: &Inputs,
+    context: &Context,
+    year: u32,
+    dir: &Path,
+    json: bool,
+) -> Result<(), String> {
+    let found = batch::find(year, dir)?;
+    if found.is_empty() {
+        return Err(format!("no inputs in {}/day<N>/", dir.display()));
+    }
+
+    let entries = batch::run(
+        year,
+        found
+            .into_iter()
+            .map(|(day, person, path)| {
+                let runner = registry::find(year, day).unwrap();
+                (runner, person, inputs.read(&path))
+            })
+            .collect(),
+        context,
+    );
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&entries).map_err(|e| e.to_string())?
+        );
+    } else {
+        batch::print(&entries);
+    }
+
+    match entries.iter().filter(|e| e.failed()).count() {
+        0 => Ok(()),
+        n => Err(format!("{n} input(s) failed")),
+    }
+}
+
+fn verify(
+    inputs: &Inputs,
+    days: &[&dyn Runner],
+    year: u32,
+    answers: &Path,
+    record: bool,
+) -> Result<(), String> {
+    let mut store = AnswerStore::load(answers)?;
+    let (mut correct, mut failed, mut new, mut missing) = (0, 0, 0, 0);
+
+    for runner in days {
+        let day = runner.day();
+        let path = inputs.path(year, day);
+
+        let Ok(input) = inputs.read(&path) else {
+            println!("Day {day}: missing input {}", path.display());
+            missing += 1;
+            continue;
+        };
+
+        let hash = input_hash(&input);
+        let parsed = match runner.parse_input(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                println!("Day {day}: {e}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let answer = match runner.solve_part(part, &parsed) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    println!("Day {day} - Part {part}: {e}");
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            match store.check(year, day, part, &hash, answer) {
+                Outcome::Correct => {
+                    println!("Day {day} - Part {part}: {answer} ok");
+                    correct += 1;
+                }
+                Outcome::Mismatch { expected } => {
+                    println!("Day {day} - Part {part}: {answer} MISMATCH, expected {expected}");
+                    failed += 1;
+                }
+                Outcome::New => {
+                    println!("Day {day} - Part {part}: {answer} new");
+                    new += 1;
+                    if record {
+                        store.record(year, day, part, &hash, answer);
+                    }
+                }
+            }
+        }
+    }
+
+    for (day, part) in store.pending(year) {
+        println!("Day {day} - Part {part}: no answer recorded yet");
+    }
+    println!("{correct} correct, {failed} failed, {new} new, {missing} missing inputs");
+
+    if record && new > 0 {
+        store.save(answers)?;
+    }
+
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{n} answer(s) failed verification")),
+    }
+}
+
+fn bench(
+    inputs: &Inputs,
+    context: &Context,
+    days: &[&dyn Runner],
+    year: u32,
+    args: BenchArgs,
+) -> Result<(), String> {
+    if args.alloc && !cfg!(feature = "alloc-profile") {
+        return Err("--alloc needs a build with the alloc-profile feature".to_string());
+    }
+
+    let history_dir = args.history_dir.join(year.to_string());
+    let baseline = args
+        .baseline
+        .map(|rev| Report::load(&history_dir.join(format!("{rev}.json"))))
+        .transpose()?;
+
+    let mut report = Report {
+        revision: bench::git_revision(),
+        iterations: args.iterations,
+        measurements: Vec::new(),
+    };
+
+    for runner in days {
+        if args.day.is_some_and(|day| day != runner.day()) {
+            continue;
+        }
+
+        let input = inputs.read(&inputs.path(year, runner.day()))?;
+        let solve = context.child().with_timeout(Some(args.timeout));
+        let measurements = bench::measure(*runner, &input, args.iterations, args.alloc, &solve)
+            .map_err(|e| e.to_string())?;
+        for part in Part::ALL {
+            if !measurements.iter().any(|m| m.phase == part.into()) {
+                eprintln!(
+                    "Day {} - Part {part}: timed out after {:?}, not benchmarked",
+                    runner.day(),
+                    args.timeout
+                );
+            }
+        }
+        report.measurements.extend(measurements);
+    }
+
+    let comparisons = baseline
+        .as_ref()
+        .map(|baseline| report.compare(baseline, args.threshold));
+
+    let alloc_header = if args.alloc {
+        format!("  {:>9}  {:>9}  {:>9}", "allocs", "bytes", "peak")
+    } else {
+        String::new()
+    };
+    println!(
+        "{:>3}  {:<6}  {:>9}  {:>9}  {:>9}{alloc_header}  {:>9}",
+        "Day", "Phase", "median", "p95", "min", "change"
+    );
+
+    for (i, m) in report.measurements.iter().enumerate() {
+        let change = match comparisons.as_ref().map(|c| &c[i]) {
+            Some(c) => match c.baseline {
+                Some(b) => {
+                    let mut change = format!(
+                        "{:+.1}%{}",
+                        bench::change(b, m),
+                        if c.regressed { " REGRESSED" } else { "" }
+                    );
+                    if let Some(bytes) = bench::alloc_change(b, m) {
+                        change += &format!(
+                            ", bytes {bytes:+.1}%{}",
+                            if c.alloc_regressed { " REGRESSED" } else { "" }
+                        );
+                    }
+                    change
+                }
+                None => "new".to_string(),
+            },
+            None => String::new(),
+        };
+
+        let alloc = match m.alloc {
+            Some(usage) => format!(
+                "  {:>9}  {:>9}  {:>9}",
+                usage.allocations,
+                bench::format_bytes(usage.bytes),
+                bench::format_bytes(usage.peak_bytes)
+            ),
+            None => String::new(),
+        };
+
+        println!(
+            "{:>3}  {:<6}  {:>9}  {:>9}  {:>9}{alloc}  {:>9}",
+            m.day,
+            m.phase.to_string(),
+            bench::format_ns(m.median_ns),
+            bench::format_ns(m.p95_ns),
+            bench::format_ns(m.min_ns),
+            change
+        );
+    }
+
+    if args.save {
+        let path = history_dir.join(format!("{}.json", report.revision));
+        report.save(&path)?;
+        println!("saved {}", path.display());
+    }
+
+    let regressions = comparisons
+        .iter()
+        .flatten()
+        .filter(|c| c.regressed || c.alloc_regressed)
+        .count();
+
+    match regressions {
+        0 => Ok(()),
+        n => Err(format!(
+            "{n} phase(s) regressed by more than {}%",
+            args.threshold
+        )),
+    }
+}
+
+fn generate(
+    year: u32,
+    day: u32,
+    seed: u64,
+    size: usize,
+    output: Option<PathBuf>,
+) -> Result<(), String> {
+    let generated = gen::generate(year, day, seed, size)
+        .ok_or_else(|| format!("no generator for {year} day {day}"))?;
+
+    match output {
+        Some(path) => fs::write(&path, &generated.input)
+            .map_err(|e| format!("writing {}: {e}", path.display()))?,
+        None => println!("{}", generated.input),
+    }
+
+    for (part, answer) in [(1, generated.part1), (2, generated.part2)] {
+        match answer {
+            Ok(answer) => eprintln!("Day {day} - Part {part}: {answer}"),
+            Err(e) => eprintln!("Day {day} - Part {part}: {e}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn report(disagreements: &[Disagreement], source: &str, input: Option<&str>) {
+    for d in disagreements {
+        println!(
+            "Day {} - Part {}: variants disagree on {source}",
+            d.day, d.part
+        );
+        for (name, result) in &d.results {
+            match result {
+                Ok(answer) => println!("  {name}: {answer}"),
+                Err(e) => println!("  {name}: {e}"),
+            }
+        }
+        if let Some(input) = input {
+            println!("{input}");
+        }
+    }
+}
+
+fn differential(
+    inputs: &Inputs,
+    days: &[&dyn Runner],
+    year: u32,
+    args: DiffArgs,
+) -> Result<(), String> {
+    let (mut checked, mut found) = (0, 0);
+
+    for runner in days {
+        let day = runner.day();
+        if args.day.is_some_and(|d| d != day) {
+            continue;
+        }
+
+        let path = args.input.clone().unwrap_or_else(|| inputs.path(year, day));
+        if args.input.is_some() || path.exists() {
+            let input = inputs.read(&path)?;
+            let disagreements = diff::check(*runner, &input).map_err(|e| e.to_string())?;
+            report(&disagreements, &path.display().to_string(), None);
+            checked += 1;
+            found += disagreements.len();
+        }
+
+        for seed in 0..args.generated {
+            let Some(generated) = gen::generate(year, day, seed, args.size) else {
+                break;
+            };
+            let disagreements =
+                diff::check(*runner, &generated.input).map_err(|e| format!("seed {seed}: {e}"))?;
+            report(
+                &disagreements,
+                &format!("seed {seed} size {}", args.size),
+                Some(&generated.input),
+            );
+            checked += 1;
+            found += disagreements.len();
+        }
+    }
+
+    if checked == 0 {
+        println!("no inputs to check; pass --input or --generated");
+    }
+    println!("{checked} input(s) checked, {found} disagreement(s)");
+
+    match found {
+        0 => Ok(()),
+        n => Err(format!("{n} part(s) had disagreeing variants")),
+    }
+}
+
+fn render(inputs: &Inputs, year: u32, day: u32, path: &Path, output: &Path) -> Result<(), String> {
+    let input = inputs.read(path)?;
+    let svg = svg::render(year, day, &input)
+        .ok_or_else(|| format!("no picture for {year} day {day}"))?
+        .map_err(|e| e.to_string())?;
+
+    fs::write(output, svg.to_string()).map_err(|e| format!("writing {}: {e}", output.display()))?;
+    println!("wrote {}", output.display());
+
+    Ok(())
+}
+
+fn explore(inputs: &Inputs, year: u32, day: u32, path: &Path) -> Result<(), String> {
+    let input = inputs.read(path)?;
+    let session = repl::Session::load(year, day, &input)
+        .ok_or_else(|| format!("no REPL for {year} day {day}"))?
+        .map_err(|e| e.to_string())?;
+
+    repl::run(&session, day, &inputs.dir.join(".repl-history"))
+}
+
+fn dump(inputs: &Inputs, runner: &dyn Runner, path: &Path) -> Result<(), String> {
+    let input = inputs.read(path)?;
+    let parsed = runner.parse_input(&input).map_err(|e| e.to_string())?;
+    let json = runner
+        .to_json(&parsed)
+        .map_err(|e| format!("day {}: {e}", runner.day()))?;
+
+    println!("{json}");
+    Ok(())
+}
+
+fn new_day(
+    root: Option<PathBuf>,
+    answers: Option<PathBuf>,
+    year: u32,
+    day: u32,
+) -> Result<(), String> {
+    let root = match root {
+        Some(root) => root,
+        None => {
+            let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
+            scaffold::find_root(&cwd)?
+        }
+    };
+
+    for path in scaffold::new_day(&root, year, day)? {
+        println!("wrote {}", root.join(path).display());
+    }
+
+    let answers = answers.unwrap_or_else(|| root.join("answers.toml"));
+    let mut store = AnswerStore::load(&answers)?;
+    store.expect(year, day);
+    store.save(&answers)?;
+    println!("wrote {}", answers.display());
+    println!(
+        "Put the puzzle's example in examples/{year}/day{day:02}/example.txt and its answers in \
+         example.answers next to it, then record the real answers with `verify --record`."
+    );
+
+    Ok(())
+}
+
+// Sends each explained day's events to stderr; nothing is logged otherwise.
+fn init_tracing(year: u32, explain: &[Explain]) {
+    if explain.is_empty() {
+        return;
+    }
+
+    let targets = explain.iter().fold(Targets::new(), |targets, e| {
+        targets.with_target(format!("adventofcode::y{year}::day{:02}", e.day), e.level)
+    });
+
+    tracing_subscriber::registry()
+        .with(fmt::layer().with_writer(io::stderr).without_time())
+        .with(targets)
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let year = cli.year;
+    init_tracing(year, &cli.explain);
+    let inputs = Inputs {
+        dir: cli.input_dir,
+        options: Options {
+            tabs_to_spaces: cli.tabs_to_spaces,
+        },
+        verbose: cli.verbose,
+    };
+    let context = Context::new().with_timeout(cli.timeout);
+    if matches!(
+        cli.command,
+        Command::Run { .. } | Command::All | Command::Batch { .. } | Command::Bench { .. }
+    ) {
+        monitor::cancel_on_ctrl_c(&context);
+    }
+
+    // Only `new-day` can work on a year that has no days yet.
+    let days = match (registry::days(year), &cli.command) {
+        (Some(days), _) => days,
+        (None, Command::NewDay { .. }) => &[],
+        (None, _) => {
+            eprintln!("error: no days of {year} are solved");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match cli.command {
+        Command::Run { day, part, input } => {
+            let path = input.unwrap_or_else(|| inputs.path(year, day));
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+            match registry::find(year, day) {
+                Some(runner) => run_day(&inputs, &context, runner, &parts, &path),
+                None => Err(format!("{year} day {day} is not solved")),
+            }
+        }
+        Command::All => {
+            let days = days
+                .iter()
+                .map(|runner| (*runner, inputs.read(&inputs.path(year, runner.day()))))
+                .collect();
+
+            let start = Instant::now();
+            let rows = all::run(days, &context);
+            all::print(&rows, start.elapsed());
+
+            match rows.iter().filter(|row| !row.ok()).count() {
+                0 => Ok(()),
+                n => Err(format!("{n} part(s) failed")),
+            }
+        }
+        Command::Batch { dir, json } => batch(&inputs, &context, year, &dir, json),
+        Command::Verify { answers, record } => verify(&inputs, days, year, &answers, record),
+        Command::Bench {
+            day,
+            iterations,
+            history_dir,
+            baseline,
+            threshold,
+            no_save,
+            alloc,
+        } => bench(
+            &inputs,
+            &context,
+            days,
+            year,
+            BenchArgs {
+                day,
+                iterations,
+                history_dir,
+                baseline,
+                threshold,
+                save: !no_save,
+                alloc,
+                timeout: cli.timeout.unwrap_or(bench::TIMEOUT),
+            },
+        ),
+        Command::Gen {
+            day,
+            seed,
+            size,
+            output,
+        } => generate(year, day, seed, size, output),
+        Command::Diff {
+            day,
+            input,
+            generated,
+            size,
+        } => differential(
+            &inputs,
+            days,
+            year,
+            DiffArgs {
+                day,
+                input,
+                generated,
+                size,
+            },
+        ),
+        Command::Render { day, input, output } => {
+            let path = input.unwrap_or_else(|| inputs.path(year, day));
+            let output = output.unwrap_or_else(|| PathBuf::from(format!("day{day}.svg")));
+            render(&inputs, year, day, &path, &output)
+        }
+        Command::Repl { day, input } => {
+            let path = input.unwrap_or_else(|| inputs.path(year, day));
+            explore(&inputs, year, day, &path)
+        }
+        Command::Dump { day, input } => {
+            let path = input.unwrap_or_else(|| inputs.path(year, day));
+            match registry::find(year, day) {
+                Some(runner) => dump(&inputs, runner, &path),
+                None => Err(format!("{year} day {day} is not solved")),
+            }
+        }
+        Command::NewDay { day, root, answers } => new_day(root, answers, year, day),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
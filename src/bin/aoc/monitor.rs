@@ -0,0 +1,119 @@
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
+use std::thread;
+use std::time::Duration;
+
+use adventofcode::context::{Context, Progress};
+
+const REDRAW: Duration = Duration::from_millis(100);
+
+// The first Ctrl-C cancels `context`, so solvers that check it stop and the
+// summary still gets printed; a second one quits straight away.
+pub fn cancel_on_ctrl_c(context: &Context) {
+    let context = context.clone();
+    let installed = ctrlc::set_handler(move || {
+        if context.is_cancelled() {
+            std::process::exit(130);
+        }
+        context.cancel();
+        eprintln!("\ncancelling, press Ctrl-C again to quit");
+    });
+
+    if let Err(e) = installed {
+        eprintln!("warning: Ctrl-C will not cancel cleanly: {e}");
+    }
+}
+
+// Keeps a line on stderr showing how far along each solve that has reported
+// progress is, for as long as `f` runs. Does nothing when stderr is not a
+// terminal.
+pub fn watch<R>(solves: &[(String, Context)], f: impl FnOnce() -> R) -> R {
+    if !io::stderr().is_terminal() {
+        return f();
+    }
+
+    let finished = AtomicBool::new(false);
+    thread::scope(|scope| {
+        let drawer = scope.spawn(|| {
+            let mut drawn = false;
+            while !finished.load(Relaxed) {
+                let line = status(solves);
+                if drawn || !line.is_empty() {
+                    eprint!("\r\x1b[K{line}");
+                    drawn = true;
+                }
+                thread::park_timeout(REDRAW);
+            }
+            if drawn {
+                eprint!("\r\x1b[K");
+            }
+        });
+
+        let result = f();
+        finished.store(true, Relaxed);
+        drawer.thread().unpark();
+        result
+    })
+}
+
+fn describe(progress: Progress) -> String {
+    match progress.total {
+        Some(total) if total > 0 => {
+            format!("{:.1}%", progress.done as f64 * 100.0 / total as f64)
+        }
+        _ => format!("{} steps", progress.done),
+    }
+}
+
+fn status(solves: &[(String, Context)]) -> String {
+    let running: Vec<_> = solves
+        .iter()
+        .filter_map(|(label, context)| Some(format!("{label}: {}", describe(context.progress()?))))
+        .collect();
+
+    running.join("  ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adventofcode::context::Ticker;
+
+    #[test]
+    fn describes_progress() {
+        assert_eq!(
+            describe(Progress {
+                done: 1,
+                total: Some(8)
+            }),
+            "12.5%"
+        );
+        assert_eq!(
+            describe(Progress {
+                done: 42,
+                total: None
+            }),
+            "42 steps"
+        );
+    }
+
+    #[test]
+    fn only_running_solves() {
+        let root = Context::new();
+        let solves = vec![
+            ("day 5 part 2".to_string(), root.child()),
+            ("day 8 part 2".to_string(), root.child()),
+        ];
+
+        let line = solves[1].1.run(|| {
+            let mut ticker = Ticker::new(Some(4 * Ticker::INTERVAL));
+            for _ in 0..Ticker::INTERVAL {
+                ticker.tick().unwrap();
+            }
+            status(&solves)
+        });
+
+        assert_eq!(line, "day 8 part 2: 25.0%");
+        assert_eq!(status(&solves), "");
+    }
+}
@@ -0,0 +1,426 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use adventofcode::error::ParseError;
+use adventofcode::y2023::day02::{Cubes, Game};
+use adventofcode::y2023::day03::{Schematic, SchematicCell};
+use adventofcode::y2023::day05::Almanac;
+use adventofcode::y2023::day07::{Card, Hand};
+use adventofcode::y2023::day08::Documents;
+use adventofcode::y2023::{day02, day03, day05, day07, day08, day09};
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+
+// A day's input, parsed once and kept for the whole session.
+pub enum Session {
+    Day02(Vec<Game>),
+    Day03(Schematic),
+    Day05(Almanac),
+    Day07(Vec<Hand>),
+    Day08(Documents),
+    Day09(Vec<Vec<i32>>),
+}
+
+const COMMON: [(&str, &str); 2] = [("help", "list commands"), ("quit", "leave the REPL")];
+
+impl Session {
+    pub fn load(year: u32, day: u32, input: &str) -> Option<Result<Self, ParseError>> {
+        Some(match (year, day) {
+            (2023, 2) => day02::input_generator(input).map(Self::Day02),
+            (2023, 3) => day03::input_generator(input).map(Self::Day03),
+            (2023, 5) => day05::input_generator(input).map(Self::Day05),
+            (2023, 7) => day07::input_generator(input).map(Self::Day07),
+            (2023, 8) => day08::input_generator(input).map(Self::Day08),
+            (2023, 9) => day09::input_generator(input).map(Self::Day09),
+            _ => return None,
+        })
+    }
+
+    // The usage and description of each of this day's commands.
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Day02(_) => &[(
+                "game ID",
+                "show a game's sets, the cubes it needs and its power",
+            )],
+            Self::Day03(_) => &[(
+                "gear X Y",
+                "show the numbers touching the gear at column X, row Y, from 0",
+            )],
+            Self::Day05(_) => &[("seed N", "follow seed N through every map to its location")],
+            Self::Day07(_) => &[(
+                "hand CARDS",
+                "show the type of CARDS with jacks and with jokers",
+            )],
+            Self::Day08(_) => &[(
+                "walk NODE [STEPS]",
+                "follow the instructions from NODE, 10 steps by default",
+            )],
+            Self::Day09(_) => &[(
+                "extrapolate N",
+                "show the differences of history N, from 1, and both extrapolations",
+            )],
+        }
+    }
+
+    fn help(&self) -> String {
+        self.commands()
+            .iter()
+            .chain(&COMMON)
+            .map(|(usage, description)| format!("{usage:<18} {description}\n"))
+            .collect()
+    }
+
+    pub fn execute(&self, line: &str) -> Result<String, String> {
+        let words: Vec<_> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(String::new());
+        };
+
+        match (self, command, args) {
+            (_, "help", []) => Ok(self.help()),
+            (Self::Day02(games), "game", [id]) => game(games, number(id)?),
+            (Self::Day03(schematic), "gear", [x, y]) => gear(schematic, (number(x)?, number(y)?)),
+            (Self::Day05(almanac), "seed", [seed]) => Ok(seed_path(almanac, number(seed)?)),
+            (Self::Day07(hands), "hand", [cards]) => hand(hands, cards),
+            (Self::Day08(documents), "walk", [node]) => walk(documents, node, 10),
+            (Self::Day08(documents), "walk", [node, steps]) => {
+                walk(documents, node, number(steps)?)
+            }
+            (Self::Day09(histories), "extrapolate", [n]) => extrapolate(histories, number(n)?),
+            _ => match self
+                .commands()
+                .iter()
+                .chain(&COMMON)
+                .find(|(usage, _)| usage.split(' ').next() == Some(command))
+            {
+                Some((usage, _)) => Err(format!("usage: {usage}")),
+                None => Err(format!("unknown command {command:?}, try help")),
+            },
+        }
+    }
+}
+
+fn number<T: std::str::FromStr>(word: &str) -> Result<T, String> {
+    word.parse()
+        .map_err(|_| format!("{word:?} is not a number"))
+}
+
+fn game(games: &[Game], id: u32) -> Result<String, String> {
+    let game = games
+        .iter()
+        .find(|game| game.id() == id)
+        .ok_or_else(|| format!("no game {id}"))?;
+
+    let mut out = String::new();
+    let (mut red, mut green, mut blue) = (0, 0, 0);
+
+    for (i, set) in game.sets().iter().enumerate() {
+        let cubes: Vec<_> = set
+            .cubes()
+            .iter()
+            .map(|cubes| match cubes {
+                Cubes::Red(n) => {
+                    red = red.max(*n);
+                    format!("{n} red")
+                }
+                Cubes::Green(n) => {
+                    green = green.max(*n);
+                    format!("{n} green")
+                }
+                Cubes::Blue(n) => {
+                    blue = blue.max(*n);
+                    format!("{n} blue")
+                }
+            })
+            .collect();
+        let _ = writeln!(out, "set {}: {}", i + 1, cubes.join(", "));
+    }
+
+    let _ = writeln!(out, "needs {red} red, {green} green, {blue} blue");
+    let _ = writeln!(
+        out,
+        "possible: {}",
+        if game.possible() { "yes" } else { "no" }
+    );
+    let _ = match game.power() {
+        Some(power) => writeln!(out, "power: {power}"),
+        None => writeln!(out, "power: does not fit in 64 bits"),
+    };
+
+    Ok(out)
+}
+
+fn gear(schematic: &Schematic, at: (usize, usize)) -> Result<String, String> {
+    if let Some((_, [a, b])) = schematic.gears().find(|&(gear, _)| gear == at) {
+        let ratio = schematic.gear_ratio([a, b]);
+        let (a, b) = (schematic.numbers()[a], schematic.numbers()[b]);
+        return Ok(format!("{a} * {b} = {ratio}\n"));
+    }
+
+    match schematic.grid().get(at) {
+        Some(SchematicCell::Symbol('*')) => Err(format!(
+            "the * at {at:?} does not touch exactly two numbers"
+        )),
+        Some(_) => Err(format!("no gear at {at:?}")),
+        None => Err(format!("{at:?} is outside the schematic")),
+    }
+}
+
+fn seed_path(almanac: &Almanac, seed: u32) -> String {
+    let path: Vec<_> = almanac.path(seed).iter().map(u32::to_string).collect();
+    format!("{}\n", path.join(" -> "))
+}
+
+fn hand(hands: &[Hand], cards: &str) -> Result<String, String> {
+    let cards: Vec<_> = cards
+        .chars()
+        .map(|c| Card::try_from(c).map_err(|_| format!("{c:?} is not a card")))
+        .collect::<Result<_, _>>()?;
+    let cards: [Card; 5] = cards
+        .try_into()
+        .map_err(|_| "a hand has five cards".to_string())?;
+
+    let hand = Hand::new(cards, 0);
+    let mut out = format!(
+        "{:?}, or {:?} with jokers\n",
+        hand.score(),
+        hand.joker_score()
+    );
+
+    for bid in hands.iter().filter(|h| h.cards() == cards).map(Hand::bid) {
+        let _ = writeln!(out, "in the input with bid {bid}");
+    }
+
+    Ok(out)
+}
+
+fn walk(documents: &Documents, start: &str, steps: usize) -> Result<String, String> {
+    let route = documents
+        .route(start)
+        .ok_or_else(|| format!("no node {start}"))?;
+    let instructions = documents.instructions().iter().cycle();
+
+    let mut out = format!("{:>5}   {start}\n", 0);
+    for (i, (node, instruction)) in route.skip(1).zip(instructions).take(steps).enumerate() {
+        let _ = writeln!(out, "{:>5} {instruction:?} {node}", i + 1);
+    }
+
+    Ok(out)
+}
+
+fn extrapolate(histories: &[Vec<i32>], n: usize) -> Result<String, String> {
+    let history = n
+        .checked_sub(1)
+        .and_then(|i| histories.get(i))
+        .ok_or_else(|| format!("no history {n}, there are {}", histories.len()))?;
+
+    let overflow = || "the extrapolation does not fit in 64 bits".to_string();
+    let rows = day09::differences(history).ok_or_else(overflow)?;
+
+    let mut out = String::new();
+    for (depth, row) in rows.iter().enumerate() {
+        let row: Vec<_> = row.iter().map(|n| format!("{n:>5}")).collect();
+        let _ = writeln!(out, "{}{}", " ".repeat(depth * 3), row.join(" "));
+    }
+    let _ = writeln!(
+        out,
+        "next: {}, previous: {}",
+        day09::next(history).ok_or_else(overflow)?,
+        day09::prev(history).ok_or_else(overflow)?
+    );
+
+    Ok(out)
+}
+
+// Completes command names; arguments are left to the user.
+struct Commands(Vec<&'static str>);
+
+impl Completer for Commands {
+    type Candidate = &'static str;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Self::Candidate>)> {
+        let prefix = &line[..pos];
+        if prefix.contains(char::is_whitespace) {
+            return Ok((pos, Vec::new()));
+        }
+
+        let matches = self
+            .0
+            .iter()
+            .copied()
+            .filter(|command| command.starts_with(prefix))
+            .collect();
+        Ok((0, matches))
+    }
+}
+
+impl Hinter for Commands {
+    type Hint = String;
+}
+
+impl Highlighter for Commands {}
+
+impl Validator for Commands {}
+
+impl Helper for Commands {}
+
+pub fn run(session: &Session, day: u32, history: &Path) -> Result<(), String> {
+    let names = session
+        .commands()
+        .iter()
+        .chain(&COMMON)
+        .filter_map(|(usage, _)| usage.split(' ').next())
+        .collect();
+
+    let mut editor: Editor<Commands, DefaultHistory> = Editor::new().map_err(|e| e.to_string())?;
+    editor.set_helper(Some(Commands(names)));
+    // A missing history file just means this is the first session.
+    let _ = editor.load_history(history);
+
+    println!("day {day} loaded, type help for commands");
+
+    loop {
+        let line = match editor.readline(&format!("day{day}> ")) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        if !line.trim().is_empty() {
+            let _ = editor.add_history_entry(line.as_str());
+        }
+        if matches!(line.trim(), "quit" | "exit") {
+            break;
+        }
+
+        match session.execute(&line) {
+            Ok(out) => print!("{out}"),
+            Err(e) => println!("error: {e}"),
+        }
+    }
+
+    if let Err(e) = save_history(&mut editor, history) {
+        eprintln!("warning: {e}");
+    }
+
+    Ok(())
+}
+
+// Creates the inputs directory if need be, since the session may have read
+// its input from somewhere else.
+fn save_history(
+    editor: &mut Editor<Commands, DefaultHistory>,
+    history: &Path,
+) -> Result<(), String> {
+    if let Some(dir) = history.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("creating {}: {e}", dir.display()))?;
+    }
+
+    editor
+        .save_history(history)
+        .map_err(|e| format!("saving history to {}: {e}", history.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(day: u32, input: &str) -> Session {
+        Session::load(2023, day, input).unwrap().unwrap()
+    }
+
+    #[test]
+    fn commands() {
+        let games = session(2, "Game 1: 3 blue, 4 red; 1 red, 2 green\nGame 2: 20 red");
+        assert_eq!(
+            games.execute("game 1"),
+            Ok("set 1: 3 blue, 4 red\nset 2: 1 red, 2 green\n\
+                needs 4 red, 2 green, 3 blue\npossible: yes\npower: 24\n"
+                .to_string())
+        );
+        assert_eq!(games.execute("game 3"), Err("no game 3".to_string()));
+
+        let schematic = session(3, "467..114..\n...*......\n..35..633.");
+        assert_eq!(
+            schematic.execute("gear 3 1"),
+            Ok("467 * 35 = 16345\n".to_string())
+        );
+        assert_eq!(
+            session(3, "99999*99999").execute("gear 5 0"),
+            Ok("99999 * 99999 = 9999800001\n".to_string())
+        );
+        assert!(schematic.execute("gear 0 0").is_err());
+
+        let almanac = session(5, "seeds: 79\n\nseed-to-soil map:\n52 50 48");
+        assert_eq!(almanac.execute("seed 79"), Ok("79 -> 81\n".to_string()));
+
+        let hands = session(7, "32T3K 765\nKTJJT 220");
+        assert_eq!(
+            hands.execute("hand KTJJT"),
+            Ok("TwoPair, or FourOfAKind with jokers\nin the input with bid 220\n".to_string())
+        );
+        assert!(hands.execute("hand KTJJ").is_err());
+
+        let network = session(
+            8,
+            "LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)",
+        );
+        assert_eq!(
+            network.execute("walk AAA 2"),
+            Ok("    0   AAA\n    1 Left BBB\n    2 Right AAA\n".to_string())
+        );
+
+        let histories = session(9, "1 3 6 10");
+        assert_eq!(
+            histories.execute("extrapolate 1").unwrap().lines().last(),
+            Some("next: 15, previous: 0")
+        );
+    }
+
+    #[test]
+    fn history() {
+        let dir = std::env::temp_dir().join(format!("aoc-repl-{}", std::process::id()));
+        let history = dir.join("inputs").join(".repl-history");
+
+        let mut editor: Editor<Commands, DefaultHistory> = Editor::new().unwrap();
+        let _ = editor.add_history_entry("game 1");
+        assert_eq!(save_history(&mut editor, &history), Ok(()));
+        assert!(fs::read_to_string(&history).unwrap().contains("game 1"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn mistakes() {
+        let histories = session(9, "1 3 6 10");
+
+        assert_eq!(histories.execute("  "), Ok(String::new()));
+        assert_eq!(
+            histories.execute("extrapolate"),
+            Err("usage: extrapolate N".to_string())
+        );
+        assert_eq!(
+            histories.execute("extrapolate x"),
+            Err("\"x\" is not a number".to_string())
+        );
+        assert!(histories
+            .execute("game 1")
+            .unwrap_err()
+            .starts_with("unknown command"));
+        assert!(histories.execute("help").unwrap().contains("extrapolate N"));
+        assert!(Session::load(2023, 1, "").is_none());
+        assert!(Session::load(2024, 2, "").is_none());
+    }
+}
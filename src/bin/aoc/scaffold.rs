@@ -0,0 +1,573 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Every place a year has to be mentioned, relative to the crate root; a day
+// is also listed in its year's `src/yYYYY/mod.rs`. `new-day` adds the day to
+// the answer store separately, since the store need not live in the crate.
+const LIB: &str = "src/lib.rs";
+const REGISTRY: &str = "src/registry.rs";
+const PRELUDE: &str = "src/prelude.rs";
+const FUZZ_MANIFEST: &str = "fuzz/Cargo.toml";
+
+const PACKAGE: &str = r#"name = "adventofcode""#;
+
+const MAX_WIDTH: usize = 100;
+
+const MODULE: &str = r#"use tracing::info;
+
+use crate::error::{ParseError, SolveError};
+use crate::normalize::{normalize, Options};
+use crate::parse::{self, signed_list};
+use crate::solution::{Answer, Solution};
+
+#[aoc_generator(day__N__)]
+pub fn input_generator(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let (input, _) = normalize(input, Options::default());
+    let lines = parse::all(__N__, &input, parse::lines(signed_list))?;
+
+    info!(lines = lines.len(), "parsed lines");
+    Ok(lines)
+}
+
+#[aoc(day__N__, part1)]
+pub fn solve_part1(_input: &[Vec<i64>]) -> Result<i64, SolveError> {
+    Err(SolveError::NoSolution("part 1 is not solved yet"))
+}
+
+#[aoc(day__N__, part2)]
+pub fn solve_part2(_input: &[Vec<i64>]) -> Result<i64, SolveError> {
+    Err(SolveError::NoSolution("part 2 is not solved yet"))
+}
+
+pub struct Day__NN__;
+
+impl Solution for Day__NN__ {
+    const DAY: u32 = __N__;
+
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    // Claims no input until this day's layout is filled in.
+    fn looks_like(_input: &str) -> bool {
+        false
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part1(input).map(Answer)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part2(input).map(Answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::examples;
+
+    examples!(Day__NN__, "__YEAR__/day__NN__": example);
+}
+"#;
+
+// Neither part is checked until the example and its answers are filled in.
+const EXAMPLE_ANSWERS: &str = "part1 = -\npart2 = -\n";
+
+const FUZZ_TARGET: &str = r#"#![no_main]
+
+use adventofcode::y__YEAR__::day__NN__;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(parsed) = day__NN__::input_generator(input) {
+        let _ = day__NN__::solve_part1(&parsed);
+        let _ = day__NN__::solve_part2(&parsed);
+    }
+});
+"#;
+
+const FUZZ_BIN: &str = r#"
+[[bin]]
+name = "y__YEAR___day__NN__"
+path = "fuzz_targets/y__YEAR___day__NN__.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+const YEAR_MODULE: &str = r#"use crate::registry::Event;
+use crate::solution::Runner;
+
+pub mod day__NN__;
+
+pub static EVENT: Event = Event {
+    year: __YEAR__,
+    days: DAYS,
+};
+
+pub static DAYS: &[&dyn Runner] = &[&day__NN__::Day__NN__];
+"#;
+
+// Without `cargo_aoc`, drops the `#[aoc...]` attribute lines, which would
+// clash with the same day of the year cargo-aoc runs.
+fn fill(template: &str, year: u32, day: u32, cargo_aoc: bool) -> String {
+    let filled = template
+        .replace("__YEAR__", &year.to_string())
+        .replace("__NN__", &format!("{day:02}"))
+        .replace("__N__", &day.to_string());
+
+    if cargo_aoc {
+        filled
+    } else {
+        filled
+            .lines()
+            .filter(|line| !line.starts_with("#[aoc"))
+            .map(|line| format!("{line}\n"))
+            .collect()
+    }
+}
+
+fn example_path(year: u32, day: u32, extension: &str) -> String {
+    format!("examples/{year}/day{day:02}/example.{extension}")
+}
+
+fn read(root: &Path, path: &str) -> Result<String, String> {
+    fs::read_to_string(root.join(path)).map_err(|e| format!("reading {path}: {e}"))
+}
+
+// Inserts `line` into the run of lines starting with `prefix`, keeping the
+// run sorted.
+fn insert_line(source: &str, prefix: &str, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let run: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(prefix))
+        .collect();
+    let last = *run.last()?;
+
+    let at = run
+        .iter()
+        .find(|&&i| lines[i] > line)
+        .copied()
+        .unwrap_or(last + 1);
+    lines.insert(at, line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+// Adds `name` to the import from `path` of the modules named like it but
+// for their number, laid out the way rustfmt would: adding `y2024` turns
+// `use crate::y2023;` into `use crate::{y2023, y2024};`.
+fn add_import(source: &str, path: &str, name: &str) -> Option<String> {
+    let kind = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    let sibling = |other: &str| {
+        other.len() > kind.len()
+            && other.starts_with(kind)
+            && other[kind.len()..].bytes().all(|b| b.is_ascii_digit())
+    };
+
+    let (start, end, mut names) = source.match_indices(path).find_map(|(start, _)| {
+        if start > 0 && !source[..start].ends_with('\n') {
+            return None;
+        }
+        let list = start + path.len();
+        let end = list + source[list..].find(';')? + 1;
+        let names: Vec<&str> = source[list..end - 1]
+            .trim_start_matches('{')
+            .trim_end_matches('}')
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        (!names.is_empty() && names.iter().all(|name| sibling(name))).then_some((start, end, names))
+    })?;
+    names.push(name);
+    names.sort_unstable();
+
+    let one_line = format!("{path}{{{}}};", names.join(", "));
+    let import = if one_line.len() <= MAX_WIDTH {
+        one_line
+    } else {
+        let mut lines = vec![format!("{path}{{")];
+        let mut line = String::from("   ");
+        for name in names {
+            if line.len() + 1 + name.len() + 1 > MAX_WIDTH {
+                lines.push(line);
+                line = String::from("   ");
+            }
+            line += &format!(" {name},");
+        }
+        lines.push(line);
+        lines.push("};".to_string());
+        lines.join("\n")
+    };
+
+    Some(format!("{}{import}{}", &source[..start], &source[end..]))
+}
+
+// Adds `entry` to the `&[...]` table of the static starting with `head`, in
+// order, on one line if it fits and one entry a line if not, as rustfmt
+// would.
+fn add_to_table(source: &str, head: &str, entry: &str) -> Option<String> {
+    let start = source.find(head)?;
+    let open = start + source[start..].find("= &[")? + 4;
+    let end = open + source[open..].find("];")?;
+
+    let mut entries: Vec<&str> = source[open..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .collect();
+    entries.push(entry);
+    entries.sort_unstable();
+
+    let declaration = &source[start..open];
+    let one_line = format!("{declaration}{}];", entries.join(", "));
+    let table = if one_line.len() <= MAX_WIDTH {
+        one_line
+    } else {
+        let lines: String = entries
+            .iter()
+            .map(|entry| format!("\n    {entry},"))
+            .collect();
+        format!("{declaration}{lines}\n];")
+    };
+
+    Some(format!("{}{table}{}", &source[..start], &source[end + 2..]))
+}
+
+// The year `aoc_lib!` hands to cargo-aoc, the only one whose days may carry
+// its attributes.
+fn cargo_aoc_year(lib: &str) -> Option<u32> {
+    let rest = &lib[lib.find("aoc_lib! { year = ")? + "aoc_lib! { year = ".len()..];
+    rest[..rest.find(|c: char| !c.is_ascii_digit())?]
+        .parse()
+        .ok()
+}
+
+// The nearest directory at or above `start` whose Cargo.toml is this
+// crate's, so `new-day` works from anywhere inside a checkout.
+pub fn find_root(start: &Path) -> Result<PathBuf, String> {
+    start
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == PACKAGE))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            format!(
+                "no Cargo.toml with {PACKAGE} in {} or above, pass --root",
+                start.display()
+            )
+        })
+}
+
+// Writes a new day's module, example and fuzz target, and adds it to its
+// year's module and the fuzz manifest. The first day of a year also creates
+// the year's module and adds it to the module list, registry and prelude.
+// Nothing is written if any of the files already exists or the day is
+// already registered. Returns every path it wrote, relative to `root`.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    if year < 2015 {
+        return Err(format!("there is no {year} event, the first was in 2015"));
+    }
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {day}, expected 1 to 25"));
+    }
+
+    let lib = read(root, LIB)?;
+    let cargo_aoc = cargo_aoc_year(&lib) == Some(year);
+    let year_module = format!("y{year}");
+    let year_path = format!("src/{year_module}/mod.rs");
+    let new_year = !lib.contains(&format!("pub mod {year_module};"));
+    let module = format!("day{day:02}");
+
+    let mut created = vec![
+        (
+            format!("src/{year_module}/{module}.rs"),
+            fill(MODULE, year, day, cargo_aoc),
+        ),
+        (example_path(year, day, "txt"), String::new()),
+        (
+            example_path(year, day, "answers"),
+            EXAMPLE_ANSWERS.to_string(),
+        ),
+        (
+            format!("fuzz/fuzz_targets/{year_module}_{module}.rs"),
+            fill(FUZZ_TARGET, year, day, cargo_aoc),
+        ),
+    ];
+    if new_year {
+        created.push((year_path.clone(), fill(YEAR_MODULE, year, day, cargo_aoc)));
+    }
+    for (path, _) in &created {
+        if root.join(path).exists() {
+            return Err(format!("{path} already exists"));
+        }
+    }
+
+    let entry = format!("&{module}::Day{day:02}");
+    let mut edited = vec![(
+        FUZZ_MANIFEST.to_string(),
+        Some(read(root, FUZZ_MANIFEST)? + &fill(FUZZ_BIN, year, day, cargo_aoc)),
+    )];
+    if new_year {
+        let registry = read(root, REGISTRY)?;
+        let event = format!("&{year_module}::EVENT");
+        edited.extend([
+            (
+                LIB.to_string(),
+                insert_line(&lib, "pub mod y", &format!("pub mod {year_module};")),
+            ),
+            (
+                REGISTRY.to_string(),
+                add_import(&registry, "use crate::", &year_module)
+                    .and_then(|registry| add_to_table(&registry, "pub static EVENTS", &event)),
+            ),
+            (
+                PRELUDE.to_string(),
+                add_import(&read(root, PRELUDE)?, "pub use crate::", &year_module),
+            ),
+        ]);
+    } else {
+        let source = read(root, &year_path)?;
+        if source.contains(&format!("pub mod {module};")) {
+            return Err(format!("{module} is already in {year_path}"));
+        }
+        let source = insert_line(&source, "pub mod day", &format!("pub mod {module};"))
+            .and_then(|source| add_to_table(&source, "pub static DAYS", &entry));
+        edited.push((year_path, source));
+    }
+
+    let mut written = Vec::new();
+    let edited: Vec<_> = edited
+        .into_iter()
+        .map(|(path, source)| {
+            source
+                .map(|source| (path.clone(), source))
+                .ok_or_else(|| format!("could not find where to add {module} in {path}"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    for (path, contents) in created.into_iter().chain(edited) {
+        let full = root.join(&path);
+        if let Some(dir) = full.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("creating {}: {e}", dir.display()))?;
+        }
+        fs::write(&full, contents).map_err(|e| format!("writing {path}: {e}"))?;
+        written.push(PathBuf::from(path));
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The files `new_day` edits, cut down to 2023's days 1 and 3, in a fresh
+    // directory named after the test.
+    fn tree(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        for (path, contents) in [
+            (
+                LIB,
+                "pub mod registry;\n\npub mod y2023;\n\naoc_lib! { year = 2023 }\n",
+            ),
+            (
+                REGISTRY,
+                "use crate::solution::Runner;\nuse crate::y2023;\n\n\
+                 pub static EVENTS: &[&Event] = &[&y2023::EVENT];\n",
+            ),
+            (PRELUDE, "pub use crate::registry;\npub use crate::y2023;\n"),
+            (
+                "src/y2023/mod.rs",
+                "use crate::solution::Runner;\n\npub mod day01;\npub mod day03;\n\n\
+                 pub static DAYS: &[&dyn Runner] = &[&day01::Day01, &day03::Day03];\n",
+            ),
+            (FUZZ_MANIFEST, "[package]\nname = \"fuzz\"\n"),
+        ] {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn adds_a_day() {
+        let root = tree("new-day");
+        let written = new_day(&root, 2023, 2).unwrap();
+        let file = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+
+        assert_eq!(written.len(), 6);
+        let module = file("src/y2023/day02.rs");
+        assert!(module.contains("impl Solution for Day02 {\n    const DAY: u32 = 2;"));
+        assert!(module.contains("#[aoc(day2, part1)]"));
+        assert!(module.contains("examples!(Day02, \"2023/day02\": example);"));
+        assert!(
+            file("fuzz/fuzz_targets/y2023_day02.rs").contains("use adventofcode::y2023::day02;")
+        );
+        assert_eq!(file("examples/2023/day02/example.txt"), "");
+        assert_eq!(file("examples/2023/day02/example.answers"), EXAMPLE_ANSWERS);
+        assert_eq!(
+            file("src/y2023/mod.rs"),
+            "use crate::solution::Runner;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\n\
+             pub static DAYS: &[&dyn Runner] = &[&day01::Day01, &day02::Day02, &day03::Day03];\n"
+        );
+        assert!(file(FUZZ_MANIFEST).ends_with("\n[[bin]]\nname = \"y2023_day02\"\npath = \"fuzz_targets/y2023_day02.rs\"\ntest = false\ndoc = false\nbench = false\n"));
+
+        let before = file("src/y2023/mod.rs");
+        assert_eq!(
+            new_day(&root, 2023, 2),
+            Err("src/y2023/day02.rs already exists".to_string())
+        );
+        assert_eq!(
+            new_day(&root, 2023, 26),
+            Err("there is no day 26, expected 1 to 25".to_string())
+        );
+        assert_eq!(file("src/y2023/mod.rs"), before);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn adds_a_year() {
+        let root = tree("new-year");
+        let written = new_day(&root, 2024, 1).unwrap();
+        let file = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+
+        assert_eq!(written.len(), 9);
+        assert_eq!(file("src/y2024/mod.rs"), fill(YEAR_MODULE, 2024, 1, false));
+        assert!(file("src/y2024/mod.rs")
+            .contains("pub static DAYS: &[&dyn Runner] = &[&day01::Day01];"));
+        assert!(!file("src/y2024/day01.rs").contains("#[aoc"));
+        assert_eq!(
+            file(LIB),
+            "pub mod registry;\n\npub mod y2023;\npub mod y2024;\n\naoc_lib! { year = 2023 }\n"
+        );
+        assert!(file(REGISTRY).contains("use crate::{y2023, y2024};"));
+        assert!(file(REGISTRY)
+            .contains("pub static EVENTS: &[&Event] = &[&y2023::EVENT, &y2024::EVENT];"));
+        assert!(file(PRELUDE).contains("pub use crate::{y2023, y2024};"));
+
+        new_day(&root, 2024, 2).unwrap();
+        assert!(file("src/y2024/mod.rs").contains("pub mod day01;\npub mod day02;\n"));
+        assert!(file("src/y2024/mod.rs")
+            .contains("pub static DAYS: &[&dyn Runner] = &[&day01::Day01, &day02::Day02];"));
+
+        assert_eq!(
+            new_day(&root, 1999, 1),
+            Err("there is no 1999 event, the first was in 2015".to_string())
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn wraps_long_imports() {
+        let names: Vec<_> = (1..=14).map(|day| format!("day{day:02}")).collect();
+        let source = format!(
+            "use crate::error::Error;\nuse crate::{{{}}};\n",
+            names.join(", ")
+        );
+
+        assert_eq!(
+            add_import(&source, "use crate::", "day15").unwrap(),
+            "use crate::error::Error;\nuse crate::{\n    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,\n    day14, day15,\n};\n"
+        );
+        assert_eq!(
+            add_import("pub use crate::registry;\n", "pub use crate::", "y2024"),
+            None
+        );
+    }
+
+    #[test]
+    fn wraps_long_tables() {
+        let source = "pub static DAYS: &[&dyn Runner] = &[&day01::Day01, &day02::Day02, &day03::Day03, &day04::Day04];\n";
+
+        assert_eq!(
+            add_to_table(source, "pub static DAYS", "&day05::Day05").unwrap(),
+            "pub static DAYS: &[&dyn Runner] = &[\n    &day01::Day01,\n    &day02::Day02,\n    &day03::Day03,\n    &day04::Day04,\n    &day05::Day05,\n];\n"
+        );
+    }
+
+    #[test]
+    fn finds_the_crate() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        assert_eq!(find_root(&root.join("src/y2023")), Ok(root.to_path_buf()));
+        assert_eq!(find_root(&root.join("fuzz")), Ok(root.to_path_buf()));
+
+        let elsewhere = tree("no-crate");
+        assert!(find_root(&elsewhere).is_err());
+        fs::remove_dir_all(&elsewhere).unwrap();
+    }
+
+    // Copies `from` into `to`, leaving out build output.
+    fn copy(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap();
+            if name == "target" {
+                continue;
+            }
+            if path.is_dir() {
+                copy(&path, &to.join(name));
+            } else {
+                fs::copy(&path, to.join(name)).unwrap();
+            }
+        }
+    }
+
+    // Scaffolds a day of this year and the first day of the next in a copy
+    // of the crate, whose library must then build and pass its tests.
+    #[test]
+    fn scaffolded_days_pass_their_tests() {
+        let crate_root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root = std::env::temp_dir().join(format!("aoc-scaffolded-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        for dir in ["src", "examples", "fuzz"] {
+            copy(&crate_root.join(dir), &root.join(dir));
+        }
+        fs::copy(crate_root.join("Cargo.toml"), root.join("Cargo.toml")).unwrap();
+        let _ = fs::copy(crate_root.join("Cargo.lock"), root.join("Cargo.lock"));
+
+        new_day(&root, 2023, 10).unwrap();
+        new_day(&root, 2024, 1).unwrap();
+
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let output = std::process::Command::new(cargo)
+            .args([
+                "test",
+                "--offline",
+                "--quiet",
+                "--no-default-features",
+                "--lib",
+            ])
+            .current_dir(&root)
+            // Shared between runs so only the first builds the dependencies.
+            .env(
+                "CARGO_TARGET_DIR",
+                std::env::temp_dir().join("aoc-scaffolded-target"),
+            )
+            .output()
+            .unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(
+            output.status.success(),
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
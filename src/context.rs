@@ -0,0 +1,228 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering::Relaxed};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::error::SolveError;
+
+// Lets a caller watch and stop a long-running solver without changing every
+// solver's signature. `Context::run` makes a context current on this thread,
+// and loops that can run for a long time report to it through a `Ticker`,
+// which is a no-op when no context is current.
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    cancelled: Arc<AtomicBool>,
+    timeout: Option<Duration>,
+    progress: Arc<Shared>,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    reporting: AtomicBool,
+    done: AtomicU64,
+    // `u64::MAX` when the solver does not know how much work is left.
+    total: AtomicU64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    pub done: u64,
+    pub total: Option<u64>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<(Context, Option<Instant>)>> = const { RefCell::new(None) };
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    // A context for one solve, cancelled along with this one and with the
+    // same time limit, which starts when it is run, but its own progress.
+    pub fn child(&self) -> Self {
+        Self {
+            cancelled: Arc::clone(&self.cancelled),
+            timeout: self.timeout,
+            progress: Arc::default(),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Relaxed)
+    }
+
+    // What the solver last reported, or `None` if it has not reported
+    // anything or has finished.
+    pub fn progress(&self) -> Option<Progress> {
+        if !self.progress.reporting.load(Relaxed) {
+            return None;
+        }
+
+        let total = self.progress.total.load(Relaxed);
+        Some(Progress {
+            done: self.progress.done.load(Relaxed),
+            total: (total != u64::MAX).then_some(total),
+        })
+    }
+
+    // Runs `f` with this context current on this thread. The previous one
+    // is restored even if `f` panics, so a caught panic cannot leave a
+    // finished context, with its deadline, current.
+    pub fn run<R>(&self, f: impl FnOnce() -> R) -> R {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let _restore = Restore {
+            context: self,
+            previous: CURRENT.replace(Some((self.clone(), deadline))),
+        };
+
+        f()
+    }
+
+    fn report(
+        &self,
+        deadline: Option<Instant>,
+        done: u64,
+        total: Option<u64>,
+    ) -> Result<(), SolveError> {
+        self.progress.done.store(done, Relaxed);
+        self.progress
+            .total
+            .store(total.unwrap_or(u64::MAX), Relaxed);
+        self.progress.reporting.store(true, Relaxed);
+
+        if self.is_cancelled() {
+            Err(SolveError::Cancelled)
+        } else if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            Err(SolveError::TimedOut)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+struct Restore<'a> {
+    context: &'a Context,
+    previous: Option<(Context, Option<Instant>)>,
+}
+
+impl Drop for Restore<'_> {
+    fn drop(&mut self) {
+        CURRENT.set(self.previous.take());
+        self.context.progress.reporting.store(false, Relaxed);
+    }
+}
+
+// Counts the iterations of a long loop, reporting to the current context
+// every `Ticker::INTERVAL` of them so checking stays cheap.
+pub struct Ticker {
+    done: u64,
+    total: Option<u64>,
+}
+
+impl Ticker {
+    pub const INTERVAL: u64 = 1 << 16;
+
+    pub fn new(total: Option<u64>) -> Self {
+        Self { done: 0, total }
+    }
+
+    // Fails once the current context has been cancelled or run out of time.
+    pub fn tick(&mut self) -> Result<(), SolveError> {
+        self.done += 1;
+        if !self.done.is_multiple_of(Self::INTERVAL) {
+            return Ok(());
+        }
+
+        CURRENT.with_borrow(|current| match current {
+            Some((context, deadline)) => context.report(*deadline, self.done, self.total),
+            None => Ok(()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spin(ticks: u64, total: Option<u64>) -> Result<u64, SolveError> {
+        let mut ticker = Ticker::new(total);
+        for _ in 0..ticks {
+            ticker.tick()?;
+        }
+        Ok(ticks)
+    }
+
+    #[test]
+    fn without_a_context() {
+        assert_eq!(spin(3 * Ticker::INTERVAL, None), Ok(3 * Ticker::INTERVAL));
+    }
+
+    #[test]
+    fn cancels() {
+        let root = Context::new();
+        let context = root.child();
+        root.cancel();
+
+        assert!(context.is_cancelled());
+        assert_eq!(
+            context.run(|| spin(3 * Ticker::INTERVAL, None)),
+            Err(SolveError::Cancelled)
+        );
+        assert_eq!(spin(3 * Ticker::INTERVAL, None), Ok(3 * Ticker::INTERVAL));
+    }
+
+    #[test]
+    fn times_out() {
+        let context = Context::new().with_timeout(Some(Duration::ZERO)).child();
+
+        assert_eq!(
+            context.run(|| spin(u64::MAX, None)),
+            Err(SolveError::TimedOut)
+        );
+        assert_eq!(context.progress(), None);
+    }
+
+    #[test]
+    fn survives_a_panic() {
+        let context = Context::new().with_timeout(Some(Duration::ZERO));
+        let panicked = std::panic::catch_unwind(|| {
+            context.run(|| {
+                let _ = spin(Ticker::INTERVAL, None);
+                panic!("a solver bug");
+            })
+        });
+
+        assert!(panicked.is_err());
+        assert_eq!(context.progress(), None);
+        assert_eq!(spin(3 * Ticker::INTERVAL, None), Ok(3 * Ticker::INTERVAL));
+    }
+
+    #[test]
+    fn reports_progress() {
+        let context = Context::new();
+        let seen = context.run(|| {
+            spin(2 * Ticker::INTERVAL + 1, Some(10 * Ticker::INTERVAL)).unwrap();
+            context.progress()
+        });
+
+        assert_eq!(
+            seen,
+            Some(Progress {
+                done: 2 * Ticker::INTERVAL,
+                total: Some(10 * Ticker::INTERVAL)
+            })
+        );
+        assert_eq!(context.progress(), None);
+    }
+}
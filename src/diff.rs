@@ -0,0 +1,71 @@
+use crate::error::{ParseError, SolveError};
+use crate::solution::{Answer, Part, Runner};
+
+// Every variant of one part whose results were not all the same, in the
+// order `Runner::variants` lists them.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Disagreement {
+    pub day: u32,
+    pub part: Part,
+    pub results: Vec<(&'static str, Result<Answer, SolveError>)>,
+}
+
+// Runs every variant of both parts on `input`. A variant that gives up on an
+// input too expensive for it is left out of the comparison.
+pub fn check(runner: &dyn Runner, input: &str) -> Result<Vec<Disagreement>, ParseError> {
+    let parsed = runner.parse_input(input)?;
+    let mut disagreements = Vec::new();
+
+    for part in Part::ALL {
+        let results: Vec<_> = runner
+            .variants(part)
+            .into_iter()
+            .filter_map(|name| Some((name, runner.solve_variant(part, name, &parsed)?)))
+            .collect();
+
+        let compared: Vec<_> = results
+            .iter()
+            .map(|(_, result)| result)
+            .filter(|result| !matches!(result, Err(SolveError::TooExpensive(_))))
+            .collect();
+
+        if compared.windows(2).any(|w| w[0] != w[1]) {
+            disagreements.push(Disagreement {
+                day: runner.day(),
+                part,
+                results,
+            });
+        }
+    }
+
+    Ok(disagreements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{gen, registry};
+
+    // Days without a generator yet, such as one just added by `new-day`, are
+    // skipped.
+    #[test]
+    fn variants_agree_on_generated_inputs() {
+        for event in registry::EVENTS {
+            for runner in event.days {
+                for seed in 0..20 {
+                    let Some(generated) = gen::generate(event.year, runner.day(), seed, 12) else {
+                        continue;
+                    };
+
+                    assert_eq!(
+                        check(*runner, &generated.input),
+                        Ok(vec![]),
+                        "{} day {} seed {seed}",
+                        event.year,
+                        runner.day()
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::error;
+use std::fmt;
+
+use nom::error::{VerboseError, VerboseErrorKind};
+
+pub type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(day: u32, line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    // `at` must be a subslice of `input`; line and column are 1-based.
+    pub fn at(day: u32, input: &str, at: &str, expected: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before[before.rfind('\n').map_or(0, |i| i + 1)..]
+            .chars()
+            .count()
+            + 1;
+
+        Self::new(day, line, column, expected)
+    }
+
+    pub fn from_nom(day: u32, input: &str, err: nom::Err<VerboseError<&str>>) -> Self {
+        let err = match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => e,
+            nom::Err::Incomplete(_) => {
+                return Self::at(day, input, &input[input.len()..], "more input")
+            }
+        };
+
+        let Some((at, kind)) = err.errors.first() else {
+            return Self::at(day, input, input, "valid input");
+        };
+
+        let expected = err
+            .errors
+            .iter()
+            .find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(ctx) => Some(ctx.to_string()),
+                _ => None,
+            })
+            .unwrap_or_else(|| match kind {
+                VerboseErrorKind::Char(c) => format!("{c:?}"),
+                VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+                VerboseErrorKind::Context(ctx) => ctx.to_string(),
+            });
+
+        Self::at(day, input, at, expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+// Raised by a solver whose input parsed but has no answer, e.g. a day08
+// network where `AAA` never reaches `ZZZ`, by a brute-force variant that
+// gave up on an input too big for it, or when the answer or a value on the
+// way to it is too big for its type. `Cancelled` and `TimedOut` come from a
+// solver stopped early through its `context::Context`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SolveError {
+    NoSolution(&'static str),
+    TooExpensive(&'static str),
+    Overflow(&'static str),
+    Cancelled,
+    TimedOut,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSolution(reason) => write!(f, "no solution: {reason}"),
+            Self::TooExpensive(reason) => write!(f, "gave up: {reason}"),
+            Self::Overflow(reason) => write!(f, "overflow: {reason}"),
+            Self::Cancelled => f.write_str("cancelled"),
+            Self::TimedOut => f.write_str("timed out"),
+        }
+    }
+}
+
+impl error::Error for SolveError {}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    UnknownYear(u32),
+    UnknownDay(u32),
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl From<SolveError> for Error {
+    fn from(err: SolveError) -> Self {
+        Self::Solve(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownYear(year) => write!(f, "no days of {year} are solved"),
+            Self::UnknownDay(day) => write!(f, "day {day} is not solved"),
+            Self::Parse(err) => err.fmt(f),
+            Self::Solve(err) => err.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::UnknownYear(_) | Self::UnknownDay(_) => None,
+            Self::Parse(err) => Some(err),
+            Self::Solve(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position() {
+        let input = "abc\ndef\nghi";
+
+        assert_eq!(
+            ParseError::at(1, input, &input[5..], "x"),
+            ParseError::new(1, 2, 2, "x")
+        );
+        assert_eq!(
+            ParseError::at(1, input, &input[input.len()..], "x"),
+            ParseError::new(1, 3, 4, "x")
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseError::new(5, 3, 7, "a number").to_string(),
+            "day 5, line 3, column 7: expected a number"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::{ptr, slice, str};
+
+use crate::error::Error;
+use crate::registry;
+use crate::solution::Part;
+
+// The C API, built into the `cdylib` and `staticlib`. Nothing here may
+// unwind into the caller, so every entry point catches panics and turns them
+// into `Aoc2023Status::Panic`. `include/aoc2023.h` is generated from this
+// file by cbindgen; `tests/ffi.rs` checks it is up to date.
+
+/// What a call returned. Anything but `AOC2023_STATUS_OK` leaves a message
+/// for `aoc2023_last_error`. Later versions may add statuses, so callers
+/// should treat any value they do not know as an error too.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aoc2023Status {
+    Ok = 0,
+    InvalidArgument = 1,
+    UnknownDay = 2,
+    InvalidUtf8 = 3,
+    ParseError = 4,
+    SolveError = 5,
+    BufferTooSmall = 6,
+    Panic = 7,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+fn fail(status: Aoc2023Status, message: impl Into<String>) -> Aoc2023Status {
+    LAST_ERROR.with(|last| *last.borrow_mut() = message.into());
+    status
+}
+
+fn panicked(payload: Box<dyn Any + Send>) -> Aoc2023Status {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic");
+    fail(Aoc2023Status::Panic, format!("panicked: {message}"))
+}
+
+// Copies as much of `s` as fits into `out`, always NUL-terminating it, and
+// returns whether all of it fit.
+unsafe fn write_c_str(s: &str, out: *mut u8, out_len: usize) -> bool {
+    if out.is_null() || out_len == 0 {
+        return s.is_empty() && out_len > 0;
+    }
+
+    let len = s.len().min(out_len - 1);
+    ptr::copy_nonoverlapping(s.as_ptr(), out, len);
+    *out.add(len) = 0;
+    len == s.len()
+}
+
+unsafe fn solve(
+    day: u32,
+    part: u32,
+    input: *const u8,
+    input_len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> Aoc2023Status {
+    if input.is_null() && input_len > 0 {
+        return fail(Aoc2023Status::InvalidArgument, "input is null");
+    }
+    if out.is_null() {
+        return fail(Aoc2023Status::InvalidArgument, "out is null");
+    }
+    let part = match Part::try_from(part) {
+        Ok(part) => part,
+        Err(e) => return fail(Aoc2023Status::InvalidArgument, e),
+    };
+
+    let bytes = if input_len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(input, input_len)
+    };
+    let input = match str::from_utf8(bytes) {
+        Ok(input) => input,
+        Err(e) => {
+            return fail(
+                Aoc2023Status::InvalidUtf8,
+                format!("input is not UTF-8: {e}"),
+            )
+        }
+    };
+
+    // The C API predates other years and stays the 2023 one.
+    match registry::solve(2023, day, part, input) {
+        Ok(answer) => {
+            let answer = answer.to_string();
+            if write_c_str(&answer, out, out_len) {
+                fail(Aoc2023Status::Ok, "")
+            } else {
+                let needed = answer.len() + 1;
+                fail(
+                    Aoc2023Status::BufferTooSmall,
+                    format!("the answer needs {needed} bytes"),
+                )
+            }
+        }
+        Err(e @ (Error::UnknownYear(_) | Error::UnknownDay(_))) => {
+            fail(Aoc2023Status::UnknownDay, e.to_string())
+        }
+        Err(e @ Error::Parse(_)) => fail(Aoc2023Status::ParseError, e.to_string()),
+        Err(e @ Error::Solve(_)) => fail(Aoc2023Status::SolveError, e.to_string()),
+    }
+}
+
+/// Solves `part` (1 or 2) of `day` for the `input_len` bytes of UTF-8 at
+/// `input`, writing the answer to `out` as a NUL-terminated decimal string.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, or may be null if
+/// `input_len` is 0. `out` must point to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc2023_solve(
+    day: u32,
+    part: u32,
+    input: *const u8,
+    input_len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> Aoc2023Status {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        solve(day, part, input, input_len, out, out_len)
+    }))
+    .unwrap_or_else(panicked)
+}
+
+/// Copies the message left by the last failed call on this thread into
+/// `out`, truncating it to fit and NUL-terminating it. Returns the length of
+/// the whole message, not counting the NUL, so a return value of `out_len`
+/// or more means it was truncated. `out` may be null if `out_len` is 0.
+///
+/// # Safety
+///
+/// `out` must point to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc2023_last_error(out: *mut u8, out_len: usize) -> usize {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        LAST_ERROR.with(|last| {
+            let last = last.borrow();
+            write_c_str(&last, out, out_len);
+            last.len()
+        })
+    }))
+    .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(day: u32, part: u32, input: &str, out_len: usize) -> (Aoc2023Status, String) {
+        let mut out = vec![0xffu8; out_len];
+        let status = unsafe {
+            aoc2023_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                out_len,
+            )
+        };
+        let end = out.iter().position(|&b| b == 0).unwrap_or(out.len());
+        (status, String::from_utf8_lossy(&out[..end]).into_owned())
+    }
+
+    fn last_error() -> String {
+        let len = unsafe { aoc2023_last_error(ptr::null_mut(), 0) };
+        let mut out = vec![0u8; len + 1];
+        assert_eq!(
+            unsafe { aoc2023_last_error(out.as_mut_ptr(), out.len()) },
+            len
+        );
+        String::from_utf8(out[..len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn solves() {
+        assert_eq!(
+            call(9, 2, "10 13 16 21 30 45\n", 16),
+            (Aoc2023Status::Ok, "5".to_string())
+        );
+        assert_eq!(last_error(), "");
+    }
+
+    #[test]
+    fn failures() {
+        assert_eq!(call(26, 1, "", 16).0, Aoc2023Status::UnknownDay);
+        assert_eq!(last_error(), "day 26 is not solved");
+
+        assert_eq!(call(9, 3, "1 2 3", 16).0, Aoc2023Status::InvalidArgument);
+        assert_eq!(last_error(), "no part 3, expected 1 or 2");
+
+        assert_eq!(call(9, 1, "1 2\n3 x", 16).0, Aoc2023Status::ParseError);
+        assert_eq!(last_error(), "day 9, line 2, column 3: expected a number");
+
+        assert_eq!(
+            call(8, 2, "L\n\nAAA = (AAA, AAA)", 16).0,
+            Aoc2023Status::SolveError
+        );
+        assert!(last_error().starts_with("no solution"));
+
+        let status = unsafe { aoc2023_solve(9, 1, [0xffu8].as_ptr(), 1, [0u8; 4].as_mut_ptr(), 4) };
+        assert_eq!(status, Aoc2023Status::InvalidUtf8);
+    }
+
+    #[test]
+    fn small_buffers() {
+        assert_eq!(
+            call(9, 1, "0 3 6 9 12 15", 2),
+            (Aoc2023Status::BufferTooSmall, "1".to_string())
+        );
+        assert_eq!(last_error(), "the answer needs 3 bytes");
+
+        let mut out = [0xffu8; 4];
+        assert_eq!(unsafe { aoc2023_last_error(out.as_mut_ptr(), 4) }, 24);
+        assert_eq!(out, *b"the\0");
+    }
+}
@@ -0,0 +1,108 @@
+use crate::normalize::windows;
+use crate::solution::{Answer, Part, Runner};
+
+// Each puzzle example lives in `examples/<year>/dayNN/<name>.txt`, next to a
+// `<name>.answers` sidecar holding a `part1 = N` and a `part2 = N` line, with
+// `-` for a part the example is not meant for.
+//
+// `examples!(Day08, "2023/day08": example1, example2)` expands to a module per
+// fixture holding its text as `INPUT` and a `part1` and `part2` test.
+macro_rules! examples {
+    ($solution:ident, $dir:literal: $($name:ident),+ $(,)?) => {
+        $(
+            mod $name {
+                use crate::solution::Part;
+
+                pub const INPUT: &str = include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/examples/",
+                    $dir,
+                    "/",
+                    stringify!($name),
+                    ".txt"
+                ));
+
+                const ANSWERS: &str = include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/examples/",
+                    $dir,
+                    "/",
+                    stringify!($name),
+                    ".answers"
+                ));
+
+                #[test]
+                fn part1() {
+                    crate::fixtures::check(&super::$solution, INPUT, ANSWERS, Part::One);
+                }
+
+                #[test]
+                fn part2() {
+                    crate::fixtures::check(&super::$solution, INPUT, ANSWERS, Part::Two);
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use examples;
+
+fn expected(answers: &str, part: Part) -> Option<Answer> {
+    let key = format!("part{part}");
+    let value = answers
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once('=')?;
+            (name.trim() == key).then(|| value.trim())
+        })
+        .unwrap_or_else(|| panic!("the answers have no {key} line"));
+
+    (value != "-").then(|| {
+        Answer(
+            value
+                .parse()
+                .unwrap_or_else(|_| panic!("invalid {key} answer {value:?}")),
+        )
+    })
+}
+
+// Solves `part` of `input` with every variant of the day, as written and
+// with Windows line endings, checking each against the sidecar's answer.
+pub fn check(runner: &dyn Runner, input: &str, answers: &str, part: Part) {
+    let Some(expected) = expected(answers, part) else {
+        return;
+    };
+
+    for (endings, input) in [("LF", input.to_string()), ("CRLF", windows(input))] {
+        assert!(
+            runner.looks_like(&input),
+            "{endings}: the example does not look like day {}",
+            runner.day()
+        );
+        let parsed = runner
+            .parse_input(&input)
+            .unwrap_or_else(|e| panic!("{endings}: {e}"));
+
+        for variant in runner.variants(part) {
+            assert_eq!(
+                runner.solve_variant(part, variant, &parsed),
+                Some(Ok(expected)),
+                "{endings}, variant {variant}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sidecars() {
+        let answers = "part1 = 142\npart2 = -\n";
+
+        assert_eq!(expected(answers, Part::One), Some(Answer(142)));
+        assert_eq!(expected(answers, Part::Two), None);
+        assert_eq!(expected(" part2=-7 ", Part::Two), Some(Answer(-7)));
+    }
+}
@@ -0,0 +1,753 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+use crate::error::SolveError;
+use crate::grid::Grid;
+use crate::solution::Answer;
+
+// SplitMix64: tiny, seedable and identical on every platform, which is all
+// a test input generator needs.
+pub struct Rng(u64);
+
+// The expected answers are `Overflow` when the input is large enough that
+// they no longer fit, which is what the solvers report too.
+pub struct Generated {
+    pub input: String,
+    pub part1: Result<Answer, SolveError>,
+    pub part2: Result<Answer, SolveError>,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in `lo..=hi`.
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi);
+        lo + (self.next_u64() % ((hi - lo) as u64 + 1)) as i64
+    }
+
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0, n as i64 - 1) as usize
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+pub fn generate(year: u32, day: u32, seed: u64, size: usize) -> Option<Generated> {
+    let rng = &mut Rng::new(seed);
+    let size = size.max(1);
+
+    Some(match (year, day) {
+        (2023, 1) => day01(rng, size),
+        (2023, 2) => day02(rng, size),
+        (2023, 3) => day03(rng, size),
+        (2023, 4) => day04(rng, size),
+        (2023, 5) => day05(rng, size),
+        (2023, 6) => day06(rng, size),
+        (2023, 7) => day07(rng, size),
+        (2023, 8) => day08(rng, size),
+        (2023, 9) => day09(rng, size),
+        _ => return None,
+    })
+}
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// Spelled digits sharing a letter, the case a naive left-to-right replace
+// gets wrong.
+const OVERLAPS: [&str; 8] = [
+    "eightwo",
+    "eighthree",
+    "oneight",
+    "twone",
+    "threeight",
+    "fiveight",
+    "sevenine",
+    "nineight",
+];
+
+pub fn day01(rng: &mut Rng, size: usize) -> Generated {
+    let digit_at = |line: &str, i: usize, words: bool| {
+        let c = line.as_bytes()[i];
+        if c.is_ascii_digit() {
+            return Some(u32::from(c - b'0'));
+        }
+
+        words
+            .then(|| DIGIT_WORDS.iter().position(|w| line[i..].starts_with(w)))
+            .flatten()
+            .map(|n| n as u32 + 1)
+    };
+    let value = |line: &str, words: bool| {
+        let first = (0..line.len())
+            .find_map(|i| digit_at(line, i, words))
+            .unwrap();
+        let last = (0..line.len())
+            .rev()
+            .find_map(|i| digit_at(line, i, words))
+            .unwrap();
+        first * 10 + last
+    };
+
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        let segments = rng.range(1, 6) as usize;
+        // Part 1 needs at least one numeric digit on every line.
+        let digit = rng.below(segments);
+
+        for i in 0..segments {
+            if i == digit || rng.chance(30) {
+                line.push(char::from(b'1' + rng.below(9) as u8));
+            }
+
+            match rng.below(3) {
+                0 => line.push_str(rng.choose::<&str>(&DIGIT_WORDS)),
+                1 => line.push_str(rng.choose::<&str>(&OVERLAPS)),
+                _ => (0..rng.range(1, 4)).for_each(|_| {
+                    line.push(char::from(b'a' + rng.below(26) as u8));
+                }),
+            }
+        }
+
+        lines.push(line);
+    }
+
+    Generated {
+        part1: Answer::try_from(
+            lines
+                .iter()
+                .map(|l| u64::from(value(l, false)))
+                .sum::<u64>(),
+        ),
+        part2: Answer::try_from(lines.iter().map(|l| u64::from(value(l, true))).sum::<u64>()),
+        input: lines.join("\n"),
+    }
+}
+
+pub fn day02(rng: &mut Rng, size: usize) -> Generated {
+    const COLORS: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+    let (mut part1, mut part2) = (0u64, 0u64);
+    let mut lines = Vec::new();
+
+    for id in 1..=size as u32 {
+        let mut max = [0; 3];
+        let mut sets = Vec::new();
+
+        for _ in 0..rng.range(1, 6) {
+            let mut colors = vec![0, 1, 2];
+            rng.shuffle(&mut colors);
+            colors.truncate(rng.range(1, 3) as usize);
+
+            let cubes: Vec<_> = colors
+                .into_iter()
+                .map(|c| {
+                    let n = rng.range(1, 16) as u32;
+                    max[c] = max[c].max(n);
+                    format!("{n} {}", COLORS[c].0)
+                })
+                .collect();
+            sets.push(cubes.join(", "));
+        }
+
+        if max.iter().zip(COLORS).all(|(n, (_, limit))| *n <= limit) {
+            part1 += u64::from(id);
+        }
+        part2 += max.iter().map(|&n| u64::from(n)).product::<u64>();
+        lines.push(format!("Game {id}: {}", sets.join("; ")));
+    }
+
+    Generated {
+        input: lines.join("\n"),
+        part1: Answer::try_from(part1),
+        part2: Answer::try_from(part2),
+    }
+}
+
+pub fn day03(rng: &mut Rng, size: usize) -> Generated {
+    const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+
+    let width = size.max(4) * 2;
+    let height = size.max(4);
+    let mut grid = vec![vec![b'.'; width]; height];
+
+    for row in &mut grid {
+        let mut x = rng.below(3);
+        while x < width {
+            if rng.chance(45) {
+                let digits = rng.range(1, 3) as usize;
+                for cell in row.iter_mut().skip(x).take(digits) {
+                    *cell = b'0' + rng.below(10) as u8;
+                }
+                x += digits + 1;
+            } else if rng.chance(30) {
+                // Favour '*' so that gears are common.
+                row[x] = if rng.chance(40) {
+                    b'*'
+                } else {
+                    *rng.choose(SYMBOLS)
+                };
+                x += 1;
+            }
+            x += rng.below(3);
+        }
+    }
+
+    // Every number as (row, first column, last column, value).
+    let mut numbers = Vec::new();
+    for (y, row) in grid.iter().enumerate() {
+        let mut x = 0;
+        while x < width {
+            if row[x].is_ascii_digit() {
+                let start = x;
+                while x < width && row[x].is_ascii_digit() {
+                    x += 1;
+                }
+                let value = std::str::from_utf8(&row[start..x])
+                    .unwrap()
+                    .parse::<u32>()
+                    .unwrap();
+                numbers.push((y, start, x - 1, value));
+            }
+            x += 1;
+        }
+    }
+
+    // Which number, if any, covers each cell, so a symbol only has to look
+    // at its eight neighbours.
+    let mut owner = Grid::from_fn(width, height, |_| None);
+    for (i, &(y, start, end, _)) in numbers.iter().enumerate() {
+        for x in start..=end {
+            owner[(x, y)] = Some(i);
+        }
+    }
+    let adjacent = |x: usize, y: usize| {
+        let mut found: Vec<usize> = owner
+            .neighbours8((x, y))
+            .filter_map(|at| owner[at])
+            .collect();
+        found.sort();
+        found.dedup();
+        found
+    };
+
+    let mut counted = vec![false; numbers.len()];
+    let mut part2 = 0u64;
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c == b'.' || c.is_ascii_digit() {
+                continue;
+            }
+            let found = adjacent(x, y);
+            for &i in &found {
+                counted[i] = true;
+            }
+            if let (b'*', [a, b]) = (c, &found[..]) {
+                part2 += u64::from(numbers[*a].3) * u64::from(numbers[*b].3);
+            }
+        }
+    }
+    let part1 = numbers
+        .iter()
+        .zip(&counted)
+        .filter(|(_, counted)| **counted)
+        .map(|(n, _)| u64::from(n.3))
+        .sum::<u64>();
+
+    let rows: Vec<_> = grid
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect();
+
+    Generated {
+        input: rows.join("\n"),
+        part1: Answer::try_from(part1),
+        part2: Answer::try_from(part2),
+    }
+}
+
+fn distinct(rng: &mut Rng, n: usize, lo: i64, hi: i64) -> Vec<i64> {
+    let mut set = BTreeSet::new();
+    while set.len() < n {
+        set.insert(rng.range(lo, hi));
+    }
+
+    let mut values: Vec<_> = set.into_iter().collect();
+    rng.shuffle(&mut values);
+    values
+}
+
+pub fn day04(rng: &mut Rng, size: usize) -> Generated {
+    let numbers = |values: &[i64]| {
+        values
+            .iter()
+            .map(|n| format!("{n:>2}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let mut lines = Vec::new();
+    let mut matches = Vec::new();
+
+    for id in 1..=size {
+        let winning = distinct(rng, 5, 1, 99);
+        let mut have = distinct(rng, 8, 1, 99);
+
+        // Random draws rarely match, so plant some winners.
+        for _ in 0..rng.below(5) {
+            let i = rng.below(have.len());
+            let w = *rng.choose(&winning);
+            if !have.contains(&w) {
+                have[i] = w;
+            }
+        }
+
+        matches.push(have.iter().filter(|n| winning.contains(n)).count());
+        lines.push(format!(
+            "Card {id:>3}: {} | {}",
+            numbers(&winning),
+            numbers(&have)
+        ));
+    }
+
+    const OVERFLOW: SolveError = SolveError::Overflow("the card count does not fit in 64 bits");
+
+    let part1 = matches
+        .iter()
+        .map(|&m| if m == 0 { 0 } else { 1u64 << (m - 1) })
+        .try_fold(0u64, u64::checked_add)
+        .ok_or(OVERFLOW);
+
+    // `None` once a card's count no longer fits.
+    let mut copies = vec![Some(1u64); size];
+    for (i, &m) in matches.iter().enumerate() {
+        for j in i + 1..(i + 1 + m).min(size) {
+            copies[j] = copies[j].zip(copies[i]).and_then(|(a, b)| a.checked_add(b));
+        }
+    }
+    let part2 = copies
+        .into_iter()
+        .try_fold(0u64, |sum, n| sum.checked_add(n?))
+        .ok_or(OVERFLOW);
+
+    Generated {
+        input: lines.join("\n"),
+        part1: part1.and_then(Answer::try_from),
+        part2: part2.and_then(Answer::try_from),
+    }
+}
+
+pub fn day05(rng: &mut Rng, size: usize) -> Generated {
+    const CATEGORIES: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+    const LIMIT: i64 = 1000;
+
+    let seeds: Vec<(i64, i64)> = (0..size)
+        .map(|_| (rng.range(0, LIMIT), rng.range(1, 50)))
+        .collect();
+
+    // Each map is a list of (dst, src, len) with disjoint source ranges.
+    let maps: Vec<Vec<(i64, i64, i64)>> = (0..CATEGORIES.len() - 1)
+        .map(|_| {
+            let items = rng.range(1, 4) as usize;
+            let mut cuts = distinct(rng, 2 * items, 0, LIMIT);
+            cuts.sort();
+            cuts.chunks(2)
+                .map(|c| (rng.range(0, LIMIT), c[0], c[1] - c[0]))
+                .collect()
+        })
+        .collect();
+
+    let location = |seed: i64| {
+        maps.iter().fold(seed, |n, map| {
+            map.iter()
+                .find(|&&(_, src, len)| (src..src + len).contains(&n))
+                .map_or(n, |&(dst, src, _)| dst + n - src)
+        })
+    };
+
+    let part1 = seeds
+        .iter()
+        .flat_map(|&(a, b)| [a, b])
+        .map(location)
+        .min()
+        .unwrap();
+    let part2 = seeds
+        .iter()
+        .flat_map(|&(start, len)| start..start + len)
+        .map(location)
+        .min()
+        .unwrap();
+
+    let mut input = String::from("seeds:");
+    for (start, len) in &seeds {
+        write!(input, " {start} {len}").unwrap();
+    }
+    for (i, map) in maps.iter().enumerate() {
+        write!(input, "\n\n{}-to-{} map:", CATEGORIES[i], CATEGORIES[i + 1]).unwrap();
+        for (dst, src, len) in map {
+            write!(input, "\n{dst} {src} {len}").unwrap();
+        }
+    }
+
+    Generated {
+        input,
+        part1: Ok(Answer(part1)),
+        part2: Ok(Answer(part2)),
+    }
+}
+
+pub fn day06(rng: &mut Rng, size: usize) -> Generated {
+    // Hold times `h` winning a race of `time` against `record`, found by
+    // binary search over the rising half of `h * (time - h)`.
+    let ways = |time: u64, record: u64| {
+        let (mut lo, mut hi) = (0, time / 2 + 1);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if mid * (time - mid) > record {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        if lo > time / 2 {
+            0
+        } else {
+            time - 2 * lo + 1
+        }
+    };
+
+    let races: Vec<(u64, u64)> = (0..size.min(3))
+        .map(|_| {
+            let time = rng.range(3, 60) as u64;
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.range(1, best as i64 - 1) as u64)
+        })
+        .collect();
+
+    let join = |values: Vec<u64>| {
+        values
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<String>()
+            .parse::<u64>()
+            .unwrap()
+    };
+
+    let part1 = races.iter().map(|&(t, d)| ways(t, d)).product::<u64>();
+    let part2 = ways(
+        join(races.iter().map(|r| r.0).collect()),
+        join(races.iter().map(|r| r.1).collect()),
+    );
+
+    let row = |label: &str, values: Vec<u64>| {
+        let mut row = label.to_string();
+        for v in values {
+            write!(row, " {v:>4}").unwrap();
+        }
+        row
+    };
+
+    Generated {
+        input: format!(
+            "{}\n{}",
+            row("Time:    ", races.iter().map(|r| r.0).collect()),
+            row("Distance:", races.iter().map(|r| r.1).collect())
+        ),
+        part1: Answer::try_from(part1),
+        part2: Answer::try_from(part2),
+    }
+}
+
+pub fn day07(rng: &mut Rng, size: usize) -> Generated {
+    const CARDS: &[u8] = b"23456789TJQKA";
+
+    // Type of a hand as its card counts, largest first; comparing these
+    // lexicographically orders hand types correctly.
+    let kind = |hand: &[u8]| {
+        let mut counts: Vec<u8> = CARDS
+            .iter()
+            .map(|c| hand.iter().filter(|h| *h == c).count() as u8)
+            .filter(|&n| n > 0)
+            .collect();
+        counts.sort_by(|a, b| b.cmp(a));
+        counts
+    };
+    let rank = |c: u8, jokers: bool| match (c, jokers) {
+        (b'J', true) => 0,
+        _ => CARDS.iter().position(|x| *x == c).unwrap() + 1,
+    };
+    let winnings = |hands: &[(Vec<u8>, u32)], jokers: bool| {
+        let mut keyed: Vec<_> = hands
+            .iter()
+            .map(|(hand, bid)| {
+                let best = if jokers {
+                    CARDS
+                        .iter()
+                        .map(|sub| {
+                            let h: Vec<u8> = hand
+                                .iter()
+                                .map(|&c| if c == b'J' { *sub } else { c })
+                                .collect();
+                            kind(&h)
+                        })
+                        .max()
+                        .unwrap()
+                } else {
+                    kind(hand)
+                };
+                let ranks: Vec<_> = hand.iter().map(|&c| rank(c, jokers)).collect();
+                ((best, ranks), *bid)
+            })
+            .collect();
+        keyed.sort();
+        keyed
+            .iter()
+            .enumerate()
+            .map(|(i, (_, bid))| (i as u64 + 1) * u64::from(*bid))
+            .sum::<u64>()
+    };
+
+    // There are only so many distinct hands. Asking for most of them deals
+    // every hand and keeps a random few, since drawing would mostly repeat.
+    let total = CARDS.len().pow(5);
+    let size = size.min(total);
+    let mut hands = Vec::new();
+    if size > total / 2 {
+        let mut deck: Vec<Vec<u8>> = (0..total)
+            .map(|mut n| {
+                (0..5)
+                    .map(|_| {
+                        let card = CARDS[n % CARDS.len()];
+                        n /= CARDS.len();
+                        card
+                    })
+                    .collect()
+            })
+            .collect();
+        rng.shuffle(&mut deck);
+        deck.truncate(size);
+        hands.extend(
+            deck.into_iter()
+                .map(|hand| (hand, rng.range(1, 1000) as u32)),
+        );
+    }
+
+    let mut seen = BTreeSet::new();
+    while hands.len() < size {
+        // Bias towards repeated cards so every hand type shows up, with some
+        // plain draws so hands of five different cards are not too rare.
+        let pool: Vec<u8> = if rng.chance(80) {
+            (0..rng.range(1, 5)).map(|_| *rng.choose(CARDS)).collect()
+        } else {
+            CARDS.to_vec()
+        };
+        let hand: Vec<u8> = (0..5).map(|_| *rng.choose(&pool)).collect();
+        if seen.insert(hand.clone()) {
+            hands.push((hand, rng.range(1, 1000) as u32));
+        }
+    }
+
+    let lines: Vec<_> = hands
+        .iter()
+        .map(|(hand, bid)| format!("{} {bid}", String::from_utf8_lossy(hand)))
+        .collect();
+
+    Generated {
+        input: lines.join("\n"),
+        part1: Answer::try_from(winnings(&hands, false)),
+        part2: Answer::try_from(winnings(&hands, true)),
+    }
+}
+
+pub fn day08(rng: &mut Rng, size: usize) -> Generated {
+    const LETTERS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+
+    let instructions: Vec<u8> = (0..rng.range(2, 5)).map(|_| *rng.choose(b"LR")).collect();
+    let n = instructions.len();
+
+    let mut names = BTreeSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+    let mut name = |rng: &mut Rng, last: Option<u8>| loop {
+        let mut s: String = (0..3).map(|_| char::from(*rng.choose(LETTERS))).collect();
+        if let Some(last) = last {
+            s.replace_range(2.., &char::from(last).to_string());
+        }
+        if names.insert(s.clone()) {
+            break s;
+        }
+    };
+
+    // Each ghost walks a private chain from its `..A` node to its `..Z`
+    // node. The chain length is a multiple of the instruction count and the
+    // `..Z` node leads back to the second node of the chain, so ghost `g`
+    // sits on `..Z` exactly every `lengths[g]` steps.
+    let mut network = HashMap::new();
+    let mut lengths = Vec::new();
+
+    for ghost in 0..size.min(4) {
+        let length = n * rng.range(1, 4) as usize;
+        let chain: Vec<String> = (0..=length)
+            .map(|i| match (ghost, i) {
+                (0, 0) => "AAA".to_string(),
+                (0, i) if i == length => "ZZZ".to_string(),
+                (_, 0) => name(rng, Some(b'A')),
+                (_, i) if i == length => name(rng, Some(b'Z')),
+                _ => name(rng, None),
+            })
+            .collect();
+
+        for i in 0..=length {
+            let next = if i == length { 1 } else { i + 1 };
+            let other = rng.choose(&chain).clone();
+            let (left, right) = match instructions[i % n] {
+                b'L' => (chain[next].clone(), other),
+                _ => (other, chain[next].clone()),
+            };
+            network.insert(chain[i].clone(), (left, right));
+        }
+
+        lengths.push(length);
+    }
+
+    let mut lines: Vec<_> = network
+        .into_iter()
+        .map(|(node, (left, right))| format!("{node} = ({left}, {right})"))
+        .collect();
+    lines.sort();
+    rng.shuffle(&mut lines);
+
+    Generated {
+        input: format!(
+            "{}\n\n{}",
+            String::from_utf8_lossy(&instructions),
+            lines.join("\n")
+        ),
+        part1: Answer::try_from(lengths[0]),
+        part2: Answer::try_from(lengths.into_iter().reduce(num::integer::lcm).unwrap()),
+    }
+}
+
+pub fn day09(rng: &mut Rng, size: usize) -> Generated {
+    let (mut part1, mut part2) = (0, 0);
+    let mut lines = Vec::new();
+
+    for _ in 0..size {
+        // Any polynomial of degree d is extrapolated exactly by d rounds of
+        // differences, given at least d + 2 samples.
+        let degree = rng.range(0, 4) as usize;
+        let coefficients: Vec<i64> = (0..=degree).map(|_| rng.range(-5, 5)).collect();
+        let eval = |x: i64| coefficients.iter().rev().fold(0, |acc, c| acc * x + c);
+        let len = rng.range(degree as i64 + 2, 21);
+
+        part1 += eval(len);
+        part2 += eval(-1);
+        lines.push(
+            (0..len)
+                .map(|x| eval(x).to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+    }
+
+    Generated {
+        input: lines.join("\n"),
+        part1: Ok(Answer(part1)),
+        part2: Ok(Answer(part2)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::registry;
+    use crate::solution::Part;
+
+    #[test]
+    fn deterministic() {
+        for day in 1..=9 {
+            let (a, b) = (
+                generate(2023, day, 7, 10).unwrap(),
+                generate(2023, day, 7, 10).unwrap(),
+            );
+            assert_eq!(a.input, b.input);
+        }
+    }
+
+    #[test]
+    fn solvers_match_oracle() {
+        for day in 1..=9 {
+            for seed in 0..20 {
+                let generated = generate(2023, day, seed, 12).unwrap();
+
+                for (part, expected) in [(Part::One, generated.part1), (Part::Two, generated.part2)]
+                {
+                    assert_eq!(
+                        registry::solve(2023, day, part, &generated.input).unwrap(),
+                        expected.unwrap(),
+                        "day {day} part {part} seed {seed}:\n{}",
+                        generated.input
+                    );
+                }
+            }
+        }
+    }
+
+    // Puzzle inputs run to a couple of hundred lines, where the answers for
+    // some days no longer fit in 32 bits and the card counts of day 4 can
+    // outgrow 64.
+    #[test]
+    fn real_sized_inputs() {
+        for day in 1..=9 {
+            for seed in 0..3 {
+                let generated = generate(2023, day, seed, 200).unwrap();
+
+                for (part, expected) in [(Part::One, generated.part1), (Part::Two, generated.part2)]
+                {
+                    let solved = registry::solve(2023, day, part, &generated.input);
+                    match expected {
+                        Ok(answer) => assert_eq!(solved.unwrap(), answer, "day {day} part {part}"),
+                        Err(e) => assert!(
+                            matches!(solved, Err(Error::Solve(SolveError::Overflow(_)))),
+                            "day {day} part {part}: expected {e}, got {solved:?}"
+                        ),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+
+// A rectangular grid stored row by row. Positions are `(x, y)` with `x`
+// counting columns from the left and `y` counting rows from the top.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "Vec<Vec<T>>",
+        try_from = "Vec<Vec<T>>",
+        bound(serialize = "T: Clone + serde::Serialize")
+    )
+)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Reads one row per line and one cell per char. `cell` returns what it
+    // expected instead when it rejects a char; every row must be as long as
+    // the first.
+    pub fn parse(
+        day: u32,
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let start = cells.len();
+
+            for (x, ch) in line.chars().enumerate() {
+                cells.push(
+                    cell(ch).map_err(|expected| ParseError::new(day, y + 1, x + 1, expected))?,
+                );
+            }
+
+            let len = cells.len() - start;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(ParseError::new(
+                        day,
+                        y + 1,
+                        len.min(width) + 1,
+                        format!("a row of {width} cells"),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    fn offset(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+
+        Some((x, y))
+    }
+
+    // The orthogonal neighbours of `at` that lie inside the grid.
+    pub fn neighbours4(&self, at: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_4.iter().filter_map(move |&d| self.offset(at, d))
+    }
+
+    // The orthogonal and diagonal neighbours of `at` that lie inside the grid.
+    pub fn neighbours8(&self, at: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8.iter().filter_map(move |&d| self.offset(at, d))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = &'static str;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        if rows.iter().any(|row| row.len() != width) {
+            return Err("every row of a grid must be the same length");
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+}
+
+impl<T: Clone> From<Grid<T>> for Vec<Vec<T>> {
+    fn from(grid: Grid<T>) -> Self {
+        grid.rows().map(<[T]>::to_vec).collect()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, at: (usize, usize)) -> &T {
+        self.get(at)
+            .unwrap_or_else(|| panic!("{at:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, at: (usize, usize)) -> &mut T {
+        self.get_mut(at)
+            .unwrap_or_else(|| panic!("{at:?} is outside the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse(0, input, Ok).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let g = grid("abc\ndef\n");
+
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g.get((2, 1)), Some(&'f'));
+        assert_eq!(g.get((3, 0)), None);
+        assert_eq!(g.to_string(), "abc\ndef");
+
+        assert_eq!(
+            Grid::parse(0, "abc\nde", Ok).err(),
+            Some(ParseError::new(0, 2, 3, "a row of 3 cells"))
+        );
+        assert_eq!(
+            Grid::parse(0, "..\n.x", |c| if c == '.' { Ok(c) } else { Err("a dot") }).err(),
+            Some(ParseError::new(0, 2, 2, "a dot"))
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let g = grid("abc\ndef\nghi");
+
+        let at = |points: Vec<_>| points.into_iter().map(|p| g[p]).collect::<String>();
+
+        assert_eq!(at(g.neighbours4((0, 0)).collect()), "bd");
+        assert_eq!(at(g.neighbours8((0, 0)).collect()), "bde");
+        assert_eq!(at(g.neighbours4((1, 1)).collect()), "bdfh");
+        assert_eq!(at(g.neighbours8((1, 1)).collect()), "abcdfghi");
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let g = grid("abc\ndef");
+
+        assert_eq!(
+            g.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(
+            g.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(g.iter().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn transformations() {
+        let g = grid("abc\ndef");
+
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(g.rotate_clockwise().rotate_counterclockwise(), g);
+    }
+
+    #[test]
+    fn rows_round_trip() {
+        let g = grid("abc\ndef");
+        let rows = Vec::<Vec<char>>::from(g.clone());
+
+        assert_eq!(Grid::try_from(rows), Ok(g));
+        assert!(Grid::try_from(vec![vec!['a'], vec![]]).is_err());
+    }
+}
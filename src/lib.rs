@@ -3,14 +3,24 @@ extern crate aoc_runner;
 #[macro_use]
 extern crate aoc_runner_derive;
 
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
+pub mod context;
+pub mod diff;
+pub mod error;
+pub mod ffi;
+#[cfg(test)]
+mod fixtures;
+pub mod gen;
+pub mod grid;
+pub mod normalize;
+pub mod parse;
+pub mod prelude;
+pub mod profile;
+pub mod registry;
+pub mod solution;
+pub mod svg;
 
+pub mod y2023;
+
+// cargo-aoc only drives a single year, and only that year's days may carry
+// its `#[aoc]` attributes; the registry is what knows every year.
 aoc_lib! { year = 2023 }
@@ -0,0 +1,170 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Options {
+    pub tabs_to_spaces: bool,
+}
+
+// What `normalize` had to change, so callers can say why an input that
+// looks fine in an editor differs from the one that was parsed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Changes {
+    pub bom: bool,
+    pub crlf: usize,
+    pub trailing_whitespace: usize,
+    pub trailing_blank_lines: usize,
+    pub tabs: usize,
+}
+
+impl Changes {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl fmt::Display for Changes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut changes = Vec::new();
+
+        if self.bom {
+            changes.push("removed a byte order mark".to_string());
+        }
+        if self.crlf > 0 {
+            changes.push(format!("converted {} CRLF line ending(s)", self.crlf));
+        }
+        if self.trailing_whitespace > 0 {
+            changes.push(format!(
+                "trimmed trailing whitespace from {} line(s)",
+                self.trailing_whitespace
+            ));
+        }
+        if self.trailing_blank_lines > 0 {
+            changes.push(format!(
+                "removed {} trailing blank line(s)",
+                self.trailing_blank_lines
+            ));
+        }
+        if self.tabs > 0 {
+            changes.push(format!("replaced {} tab(s) with spaces", self.tabs));
+        }
+
+        if changes.is_empty() {
+            write!(f, "no changes")
+        } else {
+            write!(f, "{}", changes.join(", "))
+        }
+    }
+}
+
+// Rewrites `input` to the form every day's parser expects: no byte order
+// mark, `\n` line endings, no trailing whitespace on any line, and exactly
+// one newline at the end.
+pub fn normalize(input: &str, options: Options) -> (String, Changes) {
+    let mut changes = Changes::default();
+
+    let input = match input.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            changes.bom = true;
+            rest
+        }
+        None => input,
+    };
+
+    let mut lines: Vec<&str> = input.split('\n').collect();
+    if lines.last() == Some(&"") {
+        lines.pop();
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut blank_run = 0;
+
+    for line in lines {
+        let line = match line.strip_suffix('\r') {
+            Some(line) => {
+                changes.crlf += 1;
+                line
+            }
+            None => line,
+        };
+
+        let trimmed = line.trim_end();
+        if trimmed.len() != line.len() {
+            changes.trailing_whitespace += 1;
+        }
+
+        if options.tabs_to_spaces {
+            changes.tabs += trimmed.matches('\t').count();
+            output.push_str(&trimmed.replace('\t', " "));
+        } else {
+            output.push_str(trimmed);
+        }
+        output.push('\n');
+
+        blank_run = if trimmed.is_empty() { blank_run + 1 } else { 0 };
+    }
+
+    changes.trailing_blank_lines = blank_run;
+    output.truncate(output.len() - blank_run);
+
+    (output, changes)
+}
+
+// The same input as a Windows editor might have saved it.
+#[cfg(test)]
+pub(crate) fn windows(input: &str) -> String {
+    format!("\u{feff}{}\r\n\r\n", input.replace('\n', " \r\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_input() {
+        let (output, changes) = normalize(&windows("a b\n\nc"), Options::default());
+
+        assert_eq!(output, "a b\n\nc\n");
+        assert_eq!(
+            changes,
+            Changes {
+                bom: true,
+                crlf: 4,
+                trailing_whitespace: 2,
+                trailing_blank_lines: 1,
+                tabs: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn unchanged() {
+        for input in ["a\nb\n", "a\nb", ""] {
+            let (output, changes) = normalize(input, Options::default());
+
+            assert_eq!(output.trim_end(), input.trim_end());
+            assert!(changes.is_empty(), "{input:?}: {changes}");
+        }
+    }
+
+    #[test]
+    fn tabs() {
+        let input = "Time:\t7\t15";
+
+        assert_eq!(normalize(input, Options::default()).0, "Time:\t7\t15\n");
+        assert_eq!(
+            normalize(
+                input,
+                Options {
+                    tabs_to_spaces: true
+                }
+            ),
+            (
+                "Time: 7 15\n".to_string(),
+                Changes {
+                    tabs: 2,
+                    ..Changes::default()
+                }
+            )
+        );
+    }
+}
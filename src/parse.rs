@@ -0,0 +1,210 @@
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{
+        alphanumeric1, char, multispace0, newline, none_of, one_of, space0, space1,
+    },
+    combinator::{all_consuming, cut, not, opt, peek, recognize},
+    error::{context, VerboseError, VerboseErrorKind},
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    Parser,
+};
+
+use crate::error::{IResult, ParseError};
+
+// Runs `parser` over the whole of `input`, allowing trailing whitespace, and
+// reports a failure by its line and column in `input`.
+pub fn all<'a, O>(
+    day: u32,
+    input: &'a str,
+    parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> Result<O, ParseError> {
+    all_consuming(terminated(parser, multispace0))(input)
+        .map(|(_, output)| output)
+        .map_err(|e| ParseError::from_nom(day, input, e))
+}
+
+// A whole word is taken before converting it, so `4O` is reported as a bad
+// number at its start rather than as a `4` followed by garbage. A word that
+// does not convert is a failure, not an error, so an enclosing list cannot
+// quietly stop before it.
+fn number<'a, T: FromStr>(
+    input: &'a str,
+    word: impl Parser<&'a str, &'a str, VerboseError<&'a str>>,
+) -> IResult<&'a str, T> {
+    let (rest, word) = context("a number", word)(input)?;
+
+    match word.parse() {
+        Ok(n) => Ok((rest, n)),
+        Err(_) => Err(nom::Err::Failure(VerboseError {
+            errors: vec![(word, VerboseErrorKind::Context("a number"))],
+        })),
+    }
+}
+
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    number(input, alphanumeric1)
+}
+
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    number(input, recognize(pair(opt(one_of("+-")), alphanumeric1)))
+}
+
+// Space separated items on one line, possibly indented to line up columns.
+pub fn list<'a, O>(
+    item: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    preceded(space0, separated_list1(space1, item))
+}
+
+pub fn unsigned_list<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    list(unsigned)(input)
+}
+
+pub fn signed_list<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    list(signed)(input)
+}
+
+// `label` followed by optional spaces, e.g. `seeds: 79 14` or
+// `Time:      7  15`.
+pub fn labelled<'a, O>(
+    label: &'static str,
+    value: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    preceded(pair(context(label, tag(label)), space0), value)
+}
+
+// A numbered header such as `Game 12: ` or `Card   3: `, returning the
+// number.
+pub fn numbered<'a>(label: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, u32> {
+    delimited(
+        pair(context(label, tag(label)), space1),
+        unsigned,
+        pair(char(':'), space0),
+    )
+}
+
+// One item per line. A newline followed by more text commits to another
+// line, so a bad line is reported where it goes wrong instead of ending the
+// list early; a blank line or the end of input ends it.
+pub fn lines<'a, O>(
+    line: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(terminated(newline, peek(none_of("\n"))), cut(line))
+}
+
+pub fn blank_line(input: &str) -> IResult<&str, ()> {
+    context("a blank line", pair(newline, newline))
+        .map(|_| ())
+        .parse(input)
+}
+
+// Blocks of lines separated by blank lines, committing to each block in the
+// same way as `lines`.
+pub fn sections<'a, O>(
+    section: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(
+        terminated(blank_line, not(all_consuming(multispace0))),
+        cut(section),
+    )
+}
+
+// `key <separator> value`, with optional spaces around the separator.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<&'a str, K, VerboseError<&'a str>>,
+    separator: &'static str,
+    value: impl Parser<&'a str, V, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)> {
+    separated_pair(
+        key,
+        tuple((space0, context(separator, tag(separator)), space0)),
+        value,
+    )
+}
+
+// A parenthesised pair such as `(BBB, CCC)`.
+pub fn tuple2<'a, O>(
+    item: impl Parser<&'a str, O, VerboseError<&'a str>> + Copy,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (O, O)> {
+    delimited(
+        char('('),
+        separated_pair(item, pair(char(','), space0), item),
+        char(')'),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(
+            all(0, "  7  15 30\n", unsigned_list::<u32>),
+            Ok(vec![7, 15, 30])
+        );
+        assert_eq!(all(0, "-3 0 +4", signed_list::<i32>), Ok(vec![-3, 0, 4]));
+
+        assert_eq!(
+            all(0, "9  4O  200", unsigned_list::<u32>),
+            Err(ParseError::new(0, 1, 4, "a number"))
+        );
+        assert_eq!(
+            all(0, "1 99999999999", unsigned_list::<u32>),
+            Err(ParseError::new(0, 1, 3, "a number"))
+        );
+        assert_eq!(
+            all(0, "-1", unsigned_list::<u32>),
+            Err(ParseError::new(0, 1, 1, "a number"))
+        );
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(
+            all(0, "seeds: 79 14", labelled("seeds:", unsigned_list::<u32>)),
+            Ok(vec![79, 14])
+        );
+        assert_eq!(
+            all(0, "Card   3: 1", pair(numbered("Card"), unsigned::<u32>)),
+            Ok((3, 1))
+        );
+        assert_eq!(
+            all(
+                0,
+                "Time: 7\nDistnce: 9",
+                separated_pair(
+                    labelled("Time:", unsigned::<u32>),
+                    newline,
+                    labelled("Distance:", unsigned::<u32>)
+                )
+            ),
+            Err(ParseError::new(0, 2, 1, "Distance:"))
+        );
+    }
+
+    #[test]
+    fn structure() {
+        let node = |input| alphanumeric1(input);
+
+        assert_eq!(
+            all(0, "AAA = (BBB, CCC)", key_value(node, "=", tuple2(node))),
+            Ok(("AAA", ("BBB", "CCC")))
+        );
+        assert_eq!(
+            all(0, "1 2\n3\n\n4\n", sections(lines(unsigned_list::<u32>))),
+            Ok(vec![vec![vec![1, 2], vec![3]], vec![vec![4]]])
+        );
+        assert_eq!(
+            all(0, "1 2\n3 x\n4", lines(unsigned_list::<u32>)),
+            Err(ParseError::new(0, 2, 3, "a number"))
+        );
+        assert_eq!(
+            all(0, "AAA (BBB, CCC)", key_value(node, "=", tuple2(node))),
+            Err(ParseError::new(0, 1, 5, "="))
+        );
+    }
+}
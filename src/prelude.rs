@@ -0,0 +1,9 @@
+// The types most tools built on this crate need, for a single glob import.
+// Each day's parsed input and its parts live in the day's own module under
+// its year, e.g. `y2023::day05::Almanac`.
+
+pub use crate::error::{Error, ParseError, SolveError};
+pub use crate::grid::Grid;
+pub use crate::registry;
+pub use crate::solution::{Answer, Part, Runner, Solution};
+pub use crate::y2023;
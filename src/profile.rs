@@ -0,0 +1,112 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+// Wraps the system allocator, counting every allocation. It only sees
+// anything once a binary installs it with `#[global_allocator]`.
+pub struct Counting;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Usage {
+    pub allocations: u64,
+    pub bytes: u64,
+    // Most bytes live at once, above what was already live when measuring
+    // started.
+    pub peak_bytes: u64,
+}
+
+fn allocated(size: usize) {
+    let size = size as u64;
+
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size, Relaxed);
+    let live = LIVE.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+fn freed(size: usize) {
+    LIVE.fetch_sub(size as u64, Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    // A reallocation counts as freeing the old block and allocating the new
+    // one, which is what it costs when it has to move.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+// Runs `f` and reports what it allocated. The counters are shared by the
+// whole process, so nothing else should be allocating at the same time.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Usage) {
+    let live = LIVE.load(Relaxed);
+    PEAK.store(live, Relaxed);
+    let allocations = ALLOCATIONS.load(Relaxed);
+    let bytes = BYTES.load(Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak_bytes: PEAK.load(Relaxed).saturating_sub(live),
+    };
+
+    (result, usage)
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Other tests run on other threads at the same time, so only the totals,
+    // which can only grow, are checked.
+    #[test]
+    fn counts_allocations() {
+        let (_, usage) = measure(|| {
+            let a = vec![0u8; 1000];
+            let b = vec![0u8; 500];
+            drop(a);
+            drop(b);
+            vec![0u8; 200]
+        });
+
+        assert!(usage.allocations >= 3, "{usage:?}");
+        assert!(usage.bytes >= 1700, "{usage:?}");
+    }
+}
@@ -0,0 +1,120 @@
+use crate::error::Error;
+use crate::solution::{Answer, Part, Runner};
+use crate::y2023;
+
+// One Advent of Code event: its days, in order. Each year's module, like
+// `y2023`, declares its own, and adding a year is a line here.
+pub struct Event {
+    pub year: u32,
+    pub days: &'static [&'static dyn Runner],
+}
+
+pub static EVENTS: &[&Event] = &[&y2023::EVENT];
+
+pub fn latest_year() -> u32 {
+    EVENTS.last().map_or(2023, |event| event.year)
+}
+
+pub fn days(year: u32) -> Option<&'static [&'static dyn Runner]> {
+    EVENTS
+        .iter()
+        .find(|event| event.year == year)
+        .map(|event| event.days)
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static dyn Runner> {
+    days(year)?
+        .iter()
+        .copied()
+        .find(|runner| runner.day() == day)
+}
+
+pub fn solve(year: u32, day: u32, part: Part, input: &str) -> Result<Answer, Error> {
+    let days = days(year).ok_or(Error::UnknownYear(year))?;
+    let runner = days
+        .iter()
+        .find(|runner| runner.day() == day)
+        .ok_or(Error::UnknownDay(day))?;
+    let parsed = runner.parse_input(input)?;
+
+    Ok(runner.solve_part(part, &parsed)?)
+}
+
+// Every day of `year` whose puzzle input `input` is laid out like, to tell
+// where an input filed under the wrong day was meant to go.
+pub fn looks_like(year: u32, input: &str) -> Vec<u32> {
+    days(year)
+        .unwrap_or_default()
+        .iter()
+        .filter(|runner| runner.looks_like(input))
+        .map(|runner| runner.day())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_in_order() {
+        assert!(EVENTS.windows(2).all(|w| w[0].year < w[1].year));
+        for event in EVENTS {
+            assert!(event.days.windows(2).all(|w| w[0].day() + 1 == w[1].day()));
+        }
+    }
+
+    #[test]
+    fn solve_by_day() {
+        assert_eq!(
+            solve(2023, 9, Part::Two, "10 13 16 21 30 45").unwrap(),
+            Answer(5)
+        );
+        assert!(matches!(
+            solve(2023, 9, Part::One, "10 13 x"),
+            Err(Error::Parse(_))
+        ));
+        assert!(matches!(
+            solve(2023, 26, Part::One, ""),
+            Err(Error::UnknownDay(26))
+        ));
+        assert!(matches!(
+            solve(1999, 1, Part::One, ""),
+            Err(Error::UnknownYear(1999))
+        ));
+    }
+
+    #[test]
+    fn input_layouts() {
+        assert_eq!(looks_like(2023, "Time: 7 15\nDistance: 9 40"), [6]);
+        assert_eq!(looks_like(2023, "32T3K 765\nT55J5 684"), [7]);
+        assert_eq!(looks_like(2023, "0 3 6 9 12 15\n1 3 6 10 15 21"), [9]);
+        assert_eq!(looks_like(2023, "no digits\nat all"), Vec::<u32>::new());
+        assert_eq!(looks_like(1999, "1"), Vec::<u32>::new());
+    }
+
+    // Every example looks like its own day, which `fixtures::check` asserts,
+    // and no other.
+    #[test]
+    fn examples_look_like_one_day() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+
+        for event in EVENTS {
+            for runner in event.days {
+                let day_dir = dir.join(format!("{}/day{:02}", event.year, runner.day()));
+
+                for file in std::fs::read_dir(&day_dir).unwrap() {
+                    let path = file.unwrap().path();
+                    if path.extension().is_some_and(|ext| ext == "txt") {
+                        let input = std::fs::read_to_string(&path).unwrap();
+                        let days = looks_like(event.year, &input);
+                        assert!(
+                            days.iter().all(|&day| day == runner.day()),
+                            "{} looks like days {days:?}",
+                            path.display()
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use std::any::Any;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{ParseError, SolveError};
+use crate::normalize::{normalize, Options};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Answer(pub i64);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "u32", try_from = "u32")
+)]
+pub enum Part {
+    One,
+    Two,
+}
+
+pub const PRIMARY: &str = "primary";
+
+// An alternative implementation of one part, expected to agree with the
+// primary `part1`/`part2`.
+pub struct Variant<I> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&I) -> Result<Answer, SolveError>,
+}
+
+pub trait Solution {
+    const DAY: u32;
+
+    // Shared between threads when both parts are solved at once.
+    type Input: Dump + Send + Sync + 'static;
+
+    const VARIANTS: &'static [Variant<Self::Input>] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    // Whether `input` is laid out like this day's puzzle input, to spot one
+    // filed under the wrong day. Parsers are too lenient for this: day 1's
+    // accepts almost any text with a digit in it.
+    fn looks_like(input: &str) -> bool;
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError>;
+}
+
+// Every parsed input can be dumped as JSON when the `serde` feature is on;
+// without it this bound is met by every type.
+#[cfg(feature = "serde")]
+pub trait Dump: serde::Serialize {}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> Dump for T {}
+
+#[cfg(not(feature = "serde"))]
+pub trait Dump {}
+
+#[cfg(not(feature = "serde"))]
+impl<T> Dump for T {}
+
+// Type-erased view of a `Solution` so days can be stored side by side in the
+// registry; the parsed input is only ever handed back to the day that made it.
+pub trait Runner: Sync {
+    fn day(&self) -> u32;
+
+    // Normalizes line endings and trailing whitespace before parsing, see
+    // `normalize::normalize`.
+    fn parse_input(&self, input: &str) -> Result<Parsed, ParseError>;
+
+    // Normalizes `input` the same way before checking its layout.
+    fn looks_like(&self, input: &str) -> bool;
+
+    fn solve_part(&self, part: Part, input: &Parsed) -> Result<Answer, SolveError>;
+
+    // Names of every implementation of `part`, starting with `PRIMARY`.
+    fn variants(&self, part: Part) -> Vec<&'static str>;
+
+    fn solve_variant(
+        &self,
+        part: Part,
+        name: &str,
+        input: &Parsed,
+    ) -> Option<Result<Answer, SolveError>>;
+
+    #[cfg(feature = "serde")]
+    fn to_json(&self, input: &Parsed) -> serde_json::Result<String>;
+}
+
+pub struct Parsed(Box<dyn Any + Send + Sync>);
+
+impl Parsed {
+    fn get<S: Solution>(&self) -> &S::Input {
+        self.0
+            .downcast_ref::<S::Input>()
+            .unwrap_or_else(|| panic!("input was not parsed by day {}", S::DAY))
+    }
+}
+
+impl<S: Solution + Sync> Runner for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn parse_input(&self, input: &str) -> Result<Parsed, ParseError> {
+        let (input, _) = normalize(input, Options::default());
+
+        S::parse(&input).map(|parsed| Parsed(Box::new(parsed)))
+    }
+
+    fn looks_like(&self, input: &str) -> bool {
+        let (input, _) = normalize(input, Options::default());
+
+        S::looks_like(&input)
+    }
+
+    fn solve_part(&self, part: Part, input: &Parsed) -> Result<Answer, SolveError> {
+        let input = input.get::<S>();
+
+        match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input),
+        }
+    }
+
+    fn variants(&self, part: Part) -> Vec<&'static str> {
+        let others = S::VARIANTS.iter().filter(|v| v.part == part);
+
+        std::iter::once(PRIMARY)
+            .chain(others.map(|v| v.name))
+            .collect()
+    }
+
+    fn solve_variant(
+        &self,
+        part: Part,
+        name: &str,
+        input: &Parsed,
+    ) -> Option<Result<Answer, SolveError>> {
+        if name == PRIMARY {
+            return Some(self.solve_part(part, input));
+        }
+
+        S::VARIANTS
+            .iter()
+            .find(|v| v.part == part && v.name == name)
+            .map(|v| (v.solve)(input.get::<S>()))
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_json(&self, input: &Parsed) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(input.get::<S>())
+    }
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u32> for Part {
+    type Error = String;
+
+    fn try_from(n: u32) -> Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            n => Err(format!("no part {n}, expected 1 or 2")),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<u32>()
+            .map_err(|_| format!("invalid part {s:?}, expected 1 or 2"))?
+            .try_into()
+    }
+}
+
+impl From<Part> for u32 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        u32::from(*self).fmt(f)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Self(n.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Self(n.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Self(n)
+    }
+}
+
+impl TryFrom<u64> for Answer {
+    type Error = SolveError;
+
+    fn try_from(n: u64) -> Result<Self, Self::Error> {
+        i64::try_from(n)
+            .map(Self)
+            .map_err(|_| SolveError::Overflow("the answer does not fit in 64 signed bits"))
+    }
+}
+
+impl TryFrom<usize> for Answer {
+    type Error = SolveError;
+
+    fn try_from(n: usize) -> Result<Self, Self::Error> {
+        u64::try_from(n)
+            .map_err(|_| SolveError::Overflow("the answer does not fit in 64 signed bits"))
+            .and_then(Self::try_from)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_too_big() {
+        assert_eq!(Answer::try_from(42u64), Ok(Answer(42)));
+        assert_eq!(Answer::try_from(i64::MAX as usize), Ok(Answer(i64::MAX)));
+        assert!(matches!(
+            Answer::try_from(u64::MAX),
+            Err(SolveError::Overflow(_))
+        ));
+    }
+}
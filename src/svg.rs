@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+use std::f64::consts::TAU;
+use std::fmt::{self, Write};
+
+use crate::error::{Error, SolveError};
+use crate::y2023::day03::{Schematic, SchematicCell};
+use crate::y2023::day05::Almanac;
+use crate::y2023::day08::Documents;
+use crate::y2023::{day03, day05, day08, day09};
+
+// Just enough of SVG to draw the puzzles: shapes and text appended in
+// order, so later elements are drawn on top.
+pub struct Svg {
+    width: f64,
+    height: f64,
+    body: String,
+}
+
+const FONT: &str = "font-family=\"monospace\"";
+
+const PALETTE: [&str; 8] = [
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+];
+
+impl Svg {
+    pub fn new(width: f64, height: f64) -> Self {
+        Self {
+            width,
+            height,
+            body: String::new(),
+        }
+    }
+
+    pub fn rect(&mut self, (x, y): (f64, f64), (w, h): (f64, f64), fill: &str) -> &mut Self {
+        let _ = writeln!(
+            self.body,
+            "<rect x=\"{x:.1}\" y=\"{y:.1}\" width=\"{w:.1}\" height=\"{h:.1}\" fill=\"{fill}\"/>"
+        );
+        self
+    }
+
+    pub fn line(&mut self, (x1, y1): (f64, f64), (x2, y2): (f64, f64), stroke: &str) -> &mut Self {
+        let _ = writeln!(
+            self.body,
+            "<line x1=\"{x1:.1}\" y1=\"{y1:.1}\" x2=\"{x2:.1}\" y2=\"{y2:.1}\" stroke=\"{stroke}\"/>"
+        );
+        self
+    }
+
+    pub fn circle(&mut self, (cx, cy): (f64, f64), r: f64, fill: &str) -> &mut Self {
+        let _ = writeln!(
+            self.body,
+            "<circle cx=\"{cx:.1}\" cy=\"{cy:.1}\" r=\"{r:.1}\" fill=\"{fill}\"/>"
+        );
+        self
+    }
+
+    pub fn polygon(&mut self, points: &[(f64, f64)], fill: &str, opacity: f64) -> &mut Self {
+        let points: Vec<_> = points
+            .iter()
+            .map(|(x, y)| format!("{x:.1},{y:.1}"))
+            .collect();
+        let _ = writeln!(
+            self.body,
+            "<polygon points=\"{}\" fill=\"{fill}\" fill-opacity=\"{opacity}\"/>",
+            points.join(" ")
+        );
+        self
+    }
+
+    // Text centred on `(x, y)`.
+    pub fn text(&mut self, (x, y): (f64, f64), size: f64, fill: &str, text: &str) -> &mut Self {
+        let _ = writeln!(
+            self.body,
+            "<text x=\"{x:.1}\" y=\"{y:.1}\" font-size=\"{size:.1}\" fill=\"{fill}\" {FONT} \
+             text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>",
+            escape(text)
+        );
+        self
+    }
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w:.0}\" height=\"{h:.0}\" \
+             viewBox=\"0 0 {w:.0} {h:.0}\">",
+            w = self.width,
+            h = self.height
+        )?;
+        writeln!(f, "<rect width=\"100%\" height=\"100%\" fill=\"white\"/>")?;
+        f.write_str(&self.body)?;
+        write!(f, "</svg>")
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Renders a day's input, for the days that have a picture worth drawing.
+pub fn render(year: u32, day: u32, input: &str) -> Option<Result<Svg, Error>> {
+    Some(match (year, day) {
+        (2023, 3) => day03::input_generator(input)
+            .map(|schematic| self::day03(&schematic))
+            .map_err(Error::from),
+        (2023, 5) => day05::input_generator(input)
+            .map(|almanac| self::day05(&almanac))
+            .map_err(Error::from),
+        (2023, 8) => day08::input_generator(input)
+            .map(|documents| self::day08(&documents))
+            .map_err(Error::from),
+        (2023, 9) => day09::input_generator(input)
+            .map_err(Error::from)
+            .and_then(|histories| Ok(self::day09(&histories)?)),
+        _ => return None,
+    })
+}
+
+// Part numbers in green and other numbers in grey, with gears in orange and
+// every other symbol in blue.
+pub fn day03(schematic: &Schematic) -> Svg {
+    const CELL: f64 = 14.0;
+
+    let grid = schematic.grid();
+    let parts = schematic.part_numbers();
+    let gears: Vec<_> = schematic.gears().map(|(at, _)| at).collect();
+
+    let mut svg = Svg::new(grid.width() as f64 * CELL, grid.height() as f64 * CELL);
+    let corner = |(x, y): (usize, usize)| (x as f64 * CELL, y as f64 * CELL);
+    let centre = |(x, y): (f64, f64)| (x + CELL / 2.0, y + CELL / 2.0);
+
+    for (at, cell) in grid.iter() {
+        match *cell {
+            SchematicCell::Empty => {}
+            SchematicCell::Number(n) => {
+                let fill = if parts[n] { "#a6d96a" } else { "#dddddd" };
+                svg.rect(corner(at), (CELL, CELL), fill);
+            }
+            SchematicCell::Symbol(symbol) => {
+                let fill = if gears.contains(&at) {
+                    "#fdae61"
+                } else {
+                    "#abd9e9"
+                };
+                svg.rect(corner(at), (CELL, CELL), fill);
+                svg.text(centre(corner(at)), CELL * 0.8, "black", &symbol.to_string());
+            }
+        }
+    }
+
+    // Each number is written once, centred on the cells it covers.
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let SchematicCell::Number(n) = row[x] else {
+                x += 1;
+                continue;
+            };
+
+            let len = row[x..]
+                .iter()
+                .take_while(|&&cell| cell == SchematicCell::Number(n))
+                .count();
+            let (left, top) = corner((x, y));
+            svg.text(
+                (left + len as f64 * CELL / 2.0, top + CELL / 2.0),
+                CELL * 0.8,
+                "black",
+                &schematic.numbers()[n].to_string(),
+            );
+            x += len;
+        }
+    }
+
+    svg
+}
+
+// One band per category from seeds at the top to locations at the bottom.
+// Each map item is drawn as a ribbon from its source interval on one band to
+// its destination interval on the next; values no item covers keep their
+// number and pass straight down.
+pub fn day05(almanac: &Almanac) -> Svg {
+    const WIDTH: f64 = 1000.0;
+    const MARGIN: f64 = 90.0;
+    const BAND: f64 = 12.0;
+    const GAP: f64 = 60.0;
+
+    let maps = almanac.maps();
+    let max = maps
+        .iter()
+        .flat_map(|map| map.items())
+        .flat_map(|item| [item.src().end, item.dst().end])
+        .chain(almanac.seed_ranges().map(|seeds| seeds.end))
+        .max()
+        .unwrap_or(1)
+        .max(1) as f64;
+
+    let mut svg = Svg::new(
+        WIDTH + MARGIN + 10.0,
+        (maps.len() as f64 + 1.0) * (BAND + GAP),
+    );
+    let x = |value: u64| MARGIN + value as f64 / max * WIDTH;
+    let top = |band: usize| 10.0 + band as f64 * (BAND + GAP);
+
+    for band in 0..=maps.len() {
+        let label = match band {
+            0 => "seeds".to_string(),
+            b if b == maps.len() => "locations".to_string(),
+            b => format!("map {b}"),
+        };
+        svg.rect((MARGIN, top(band)), (WIDTH, BAND), "#eeeeee");
+        svg.text(
+            (MARGIN / 2.0, top(band) + BAND / 2.0),
+            12.0,
+            "black",
+            &label,
+        );
+    }
+
+    for seeds in almanac.seed_ranges() {
+        svg.rect(
+            (x(seeds.start), top(0)),
+            (x(seeds.end) - x(seeds.start), BAND),
+            "#333333",
+        );
+    }
+
+    for (i, map) in maps.iter().enumerate() {
+        let (from, to) = (top(i) + BAND, top(i + 1));
+
+        for (j, item) in map.items().iter().enumerate() {
+            let (src, dst) = (item.src(), item.dst());
+            svg.polygon(
+                &[
+                    (x(src.start), from),
+                    (x(src.end), from),
+                    (x(dst.end), to),
+                    (x(dst.start), to),
+                ],
+                PALETTE[j % PALETTE.len()],
+                0.6,
+            );
+        }
+    }
+
+    svg
+}
+
+// Nodes around a circle with every left and right edge in grey, and the
+// walk from `AAA` to `ZZZ` in red when the network has one.
+pub fn day08(documents: &Documents) -> Svg {
+    const SIZE: f64 = 800.0;
+    const RADIUS: f64 = 360.0;
+
+    let network = documents.network();
+    let mut nodes: Vec<_> = network.nodes().collect();
+    nodes.sort();
+
+    let position: HashMap<&str, (f64, f64)> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &node)| {
+            let angle = i as f64 / nodes.len() as f64 * TAU;
+            let at = (
+                SIZE / 2.0 + RADIUS * angle.cos(),
+                SIZE / 2.0 + RADIUS * angle.sin(),
+            );
+            (node, at)
+        })
+        .collect();
+
+    let mut svg = Svg::new(SIZE, SIZE);
+
+    for &node in &nodes {
+        let (left, right) = network.get(node).unwrap();
+        for dest in [left, right] {
+            svg.line(position[node], position[dest], "#cccccc");
+        }
+    }
+
+    let walk = documents
+        .walk("AAA", |node| node == "ZZZ")
+        .unwrap_or_default();
+    for step in walk.windows(2) {
+        svg.line(position[step[0]], position[step[1]], "#d62728");
+    }
+
+    for &node in &nodes {
+        let fill = if walk.contains(&node) {
+            "#d62728"
+        } else {
+            "#4e79a7"
+        };
+        svg.circle(position[node], 3.0, fill);
+    }
+
+    for node in ["AAA", "ZZZ"] {
+        if let Some(&(x, y)) = position.get(node) {
+            svg.text((x, y - 10.0), 12.0, "black", node);
+        }
+    }
+
+    svg
+}
+
+// Each history above its rows of differences, indented by half a cell per
+// row so every difference sits between the two values it came from.
+pub fn day09(histories: &[Vec<i32>]) -> Result<Svg, SolveError> {
+    const CELL: f64 = 40.0;
+    const ROW: f64 = 18.0;
+
+    let triangles: Vec<_> = histories
+        .iter()
+        .map(|h| day09::differences(h))
+        .collect::<Option<_>>()
+        .ok_or(SolveError::Overflow("a difference does not fit in 64 bits"))?;
+    let columns = histories.iter().map(Vec::len).max().unwrap_or(0);
+    let rows: usize = triangles.iter().map(|t| t.len() + 1).sum();
+
+    let mut svg = Svg::new(columns as f64 * CELL, rows as f64 * ROW);
+    let mut y = ROW / 2.0;
+
+    for triangle in &triangles {
+        for (depth, row) in triangle.iter().enumerate() {
+            let fill = if row.iter().all(|n| *n == 0) {
+                "#999999"
+            } else {
+                PALETTE[depth % PALETTE.len()]
+            };
+
+            for (i, n) in row.iter().enumerate() {
+                let x = (i as f64 + 0.5) * CELL + depth as f64 * CELL / 2.0;
+                svg.text((x, y), 12.0, fill, &n.to_string());
+            }
+            y += ROW;
+        }
+        y += ROW;
+    }
+
+    Ok(svg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(svg: &Svg, needle: &str) -> usize {
+        svg.to_string().matches(needle).count()
+    }
+
+    #[test]
+    fn document() {
+        let mut svg = Svg::new(20.0, 10.0);
+        svg.text((5.0, 5.0), 8.0, "black", "a<b & c");
+
+        let s = svg.to_string();
+        assert!(s.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"20\""));
+        assert!(s.contains(">a&lt;b &amp; c</text>"));
+        assert!(s.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn days() {
+        let schematic = render(2023, 3, "467..114..\n...*......\n..35..633.\n").unwrap();
+        let schematic = schematic.unwrap();
+        assert_eq!(count(&schematic, "#a6d96a"), 5);
+        assert_eq!(count(&schematic, "#dddddd"), 6);
+        assert_eq!(count(&schematic, "#fdae61"), 1);
+
+        let almanac = render(2023, 5, "seeds: 1 2\n\nseed-to-soil map:\n5 0 3\n9 3 1\n");
+        assert_eq!(count(&almanac.unwrap().unwrap(), "<polygon"), 2);
+
+        let almanac = render(
+            2023,
+            5,
+            "seeds: 4294967295 4294967295\n\nseed-to-soil map:\n0 0 1\n",
+        );
+        let almanac = almanac.unwrap().unwrap();
+        assert_eq!(count(&almanac, "x=\"590.0\" y=\"10.0\" width=\"500.0\""), 1);
+
+        let network = render(
+            2023,
+            8,
+            "RL\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)",
+        );
+        let network = network.unwrap().unwrap();
+        assert_eq!(count(&network, "<line"), 6 + 1);
+        assert_eq!(count(&network, "fill=\"#d62728\""), 2);
+
+        let histories = render(2023, 9, "1 3 6 10").unwrap().unwrap();
+        assert_eq!(count(&histories, "<text"), 4 + 3 + 2 + 1);
+
+        assert!(render(2023, 1, "1abc2").is_none());
+        assert!(render(2023, 9, "1 x").unwrap().is_err());
+    }
+
+    #[test]
+    fn overflow() {
+        let histories = render(2023, 9, "2147483647 -2147483648").unwrap().unwrap();
+        assert_eq!(count(&histories, ">-4294967295</text>"), 1);
+
+        let history = [i32::MIN, i32::MAX].repeat(35);
+        assert_eq!(
+            day09(&[history]).err(),
+            Some(SolveError::Overflow("a difference does not fit in 64 bits"))
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::str::FromStr;
+
+use nom::{branch::alt, bytes::complete::tag, combinator::map};
+use tracing::{debug, info};
+
+use crate::error::{IResult, ParseError, SolveError};
+use crate::normalize::{normalize, Options};
+use crate::solution::{Answer, Solution};
+
+fn one(input: &str) -> IResult<&str, u32> {
+    map(alt((tag("one"), tag("1"))), |_| 1)(input)
+}
+
+fn two(input: &str) -> IResult<&str, u32> {
+    map(alt((tag("two"), tag("2"))), |_| 2)(input)
+}
+
+fn three(input: &str) -> IResult<&str, u32> {
+    map(alt((tag("three"), tag("3"))), |_| 3)(input)
+}
+
+fn four(input: &str) -> IResult<&str, u32> {
+    map(alt((tag("four"), tag("4"))), |_| 4)(input)
+}
+
+fn five(input: &str) -> IResult<&str, u32> {
+    map(alt((tag("five"), tag("5"))), |_| 5)(input)
+}
+
+fn six(input: &str) -> IResult<&str, u32> {
+    map(alt((tag("six"), tag("6"))), |_| 6)(input)
+}
+
+fn seven(input: &str) -> IResult<&str, u32> {
+    map(alt((tag("seven"), tag("7"))), |_| 7)(input)
+}
+
+fn eight(input: &str) -> IResult<&str, u32> {
+    map(alt((tag("eight"), tag("8"))), |_| 8)(input)
+}
+
+fn nine(input: &str) -> IResult<&str, u32> {
+    map(alt((tag("nine"), tag("9"))), |_| 9)(input)
+}
+
+fn parse_digit(input: &str) -> IResult<&str, u32> {
+    alt((one, two, three, four, five, six, seven, eight, nine))(input)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+pub struct Calibration(String);
+
+impl Calibration {
+    // `None` unless the line has a digit, spelled out or not.
+    pub fn new(line: impl Into<String>) -> Option<Self> {
+        let line = line.into();
+
+        (0..line.len())
+            .any(|i| line.is_char_boundary(i) && parse_digit(&line[i..]).is_ok())
+            .then_some(Self(line))
+    }
+
+    pub fn line(&self) -> &str {
+        &self.0
+    }
+
+    fn as_digits(&self) -> Option<u32> {
+        let (mut first, mut last) = (None, None);
+
+        for c in self.0.chars() {
+            if let Some(i) = c.to_digit(10) {
+                if first.is_none() {
+                    first = Some(i);
+                }
+                last = Some(i);
+            }
+        }
+
+        Some(first? * 10 + last?)
+    }
+
+    fn as_letters(&self) -> u32 {
+        let digit_at = |(cur, _)| parse_digit(&self.0[cur..]).ok().map(|(_, i)| i);
+
+        let first = self.0.char_indices().find_map(digit_at);
+        let last = self.0.char_indices().rev().find_map(digit_at);
+
+        first.expect("checked by input_generator") * 10 + last.expect("checked by input_generator")
+    }
+}
+
+impl TryFrom<String> for Calibration {
+    type Error = &'static str;
+
+    fn try_from(line: String) -> Result<Self, Self::Error> {
+        Self::new(line).ok_or("a calibration line must contain a digit")
+    }
+}
+
+impl From<Calibration> for String {
+    fn from(calibration: Calibration) -> Self {
+        calibration.0
+    }
+}
+
+impl FromStr for Calibration {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or_else(|| ParseError::new(1, 1, 1, "a line containing a digit"))
+    }
+}
+
+#[aoc_generator(day1)]
+pub fn input_generator(input: &str) -> Result<Vec<Calibration>, ParseError> {
+    let (input, _) = normalize(input, Options::default());
+    let calibrations = input
+        .lines()
+        .map(|line| {
+            Calibration::new(line)
+                .ok_or_else(|| ParseError::at(1, &input, line, "a line containing a digit"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    info!(lines = calibrations.len(), "parsed calibration document");
+    Ok(calibrations)
+}
+
+#[aoc(day1, part1)]
+pub fn solve_part1(input: &[Calibration]) -> Result<u32, SolveError> {
+    input
+        .iter()
+        .map(|c| {
+            let value = c
+                .as_digits()
+                .ok_or(SolveError::NoSolution("a line has no numeric digit"))?;
+            debug!(line = c.0, value, "calibration value");
+            Ok(value)
+        })
+        .sum()
+}
+
+#[aoc(day1, part2)]
+pub fn solve_part2(input: &[Calibration]) -> u32 {
+    input
+        .iter()
+        .map(|c| {
+            let value = c.as_letters();
+            debug!(line = c.0, value, "calibration value");
+            value
+        })
+        .sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Input = Vec<Calibration>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    // Lines of letters and digits and nothing else.
+    fn looks_like(input: &str) -> bool {
+        !input.is_empty()
+            && input
+                .lines()
+                .all(|line| !line.is_empty() && line.bytes().all(|b| b.is_ascii_alphanumeric()))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::examples;
+
+    examples!(Day01, "2023/day01": example1, example2);
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            input_generator("1abc2\nabcdef").err(),
+            Some(ParseError::new(1, 2, 1, "a line containing a digit"))
+        );
+        assert_eq!(
+            solve_part1(&input_generator("1abc2\nabctwodef").unwrap()),
+            Err(SolveError::NoSolution("a line has no numeric digit"))
+        );
+        assert_eq!(solve_part2(&input_generator("é1ö").unwrap()), 11);
+    }
+}
@@ -0,0 +1,253 @@
+use std::str::FromStr;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::map,
+    error::context,
+    multi::separated_list1,
+    sequence::{pair, terminated, tuple},
+};
+use tracing::{debug, info};
+
+use crate::error::{IResult, ParseError, SolveError};
+use crate::normalize::{normalize, Options};
+use crate::parse::{self, numbered, unsigned};
+use crate::solution::{Answer, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Game {
+    id: u32,
+    sets: Vec<Set>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Set(Vec<Cubes>);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Cubes {
+    Red(u32),
+    Green(u32),
+    Blue(u32),
+}
+
+impl Game {
+    pub fn new(id: u32, sets: Vec<Set>) -> Self {
+        Self { id, sets }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn sets(&self) -> &[Set] {
+        &self.sets
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                numbered("Game"),
+                separated_list1(tag("; "), context("a set of cubes", Set::parse)),
+            )),
+            |(id, sets)| Self { id, sets },
+        )(input)
+    }
+
+    // Whether every set could have been drawn from 12 red, 13 green and 14
+    // blue cubes.
+    pub fn possible(&self) -> bool {
+        self.sets.iter().all(Set::possible)
+    }
+
+    // The product of the fewest red, green and blue cubes the game needs, or
+    // `None` if it does not fit in 64 bits.
+    pub fn power(&self) -> Option<u64> {
+        let (mut red, mut green, mut blue) = (u32::MIN, u32::MIN, u32::MIN);
+
+        for set in &self.sets {
+            for cube in &set.0 {
+                match cube {
+                    Cubes::Red(n) => red = red.max(*n),
+                    Cubes::Green(n) => green = green.max(*n),
+                    Cubes::Blue(n) => blue = blue.max(*n),
+                };
+            }
+        }
+
+        u64::from(red)
+            .checked_mul(u64::from(green))?
+            .checked_mul(u64::from(blue))
+    }
+}
+
+impl Set {
+    pub fn new(cubes: Vec<Cubes>) -> Self {
+        Self(cubes)
+    }
+
+    pub fn cubes(&self) -> &[Cubes] {
+        &self.0
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            separated_list1(
+                tag(", "),
+                context("a count of red, green or blue cubes", Cubes::parse),
+            ),
+            Self,
+        )(input)
+    }
+
+    fn possible(&self) -> bool {
+        self.0.iter().all(Cubes::possible)
+    }
+}
+
+impl Cubes {
+    pub fn count(&self) -> u32 {
+        match self {
+            Self::Red(n) | Self::Green(n) | Self::Blue(n) => *n,
+        }
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(terminated(unsigned, pair(space1, tag("red"))), Cubes::Red),
+            map(
+                terminated(unsigned, pair(space1, tag("green"))),
+                Cubes::Green,
+            ),
+            map(terminated(unsigned, pair(space1, tag("blue"))), Cubes::Blue),
+        ))(input)
+    }
+
+    fn possible(&self) -> bool {
+        match self {
+            Self::Red(n) => *n <= 12,
+            Self::Green(n) => *n <= 13,
+            Self::Blue(n) => *n <= 14,
+        }
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::all(2, s, Game::parse)
+    }
+}
+
+#[aoc_generator(day2)]
+pub fn input_generator(input: &str) -> Result<Vec<Game>, ParseError> {
+    let (input, _) = normalize(input, Options::default());
+    let games = parse::all(2, &input, parse::lines(Game::parse))?;
+
+    info!(games = games.len(), "parsed games");
+    Ok(games)
+}
+
+#[aoc(day2, part1)]
+pub fn solve_part1(input: &[Game]) -> u64 {
+    input
+        .iter()
+        .filter_map(|game| {
+            let possible = game.possible();
+            debug!(game = game.id, possible);
+            possible.then_some(u64::from(game.id))
+        })
+        .sum()
+}
+
+#[aoc(day2, part2)]
+pub fn solve_part2(input: &[Game]) -> Result<u64, SolveError> {
+    input.iter().try_fold(0u64, |sum, game| {
+        let power = game.power();
+        debug!(game = game.id, power);
+        power
+            .and_then(|power| sum.checked_add(power))
+            .ok_or(SolveError::Overflow(
+                "the sum of powers does not fit in 64 bits",
+            ))
+    })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    fn looks_like(input: &str) -> bool {
+        input.starts_with("Game ")
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Answer::try_from(solve_part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part2(input).and_then(Answer::try_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::examples;
+
+    examples!(Day02, "2023/day02": example);
+
+    #[test]
+    fn accessors() {
+        let game: Game = "Game 7: 3 blue, 4 red; 2 green".parse().unwrap();
+
+        assert_eq!(game.id(), 7);
+        assert_eq!(
+            game.sets(),
+            [
+                Set::new(vec![Cubes::Blue(3), Cubes::Red(4)]),
+                Set::new(vec![Cubes::Green(2)])
+            ]
+        );
+        assert_eq!(game.sets()[0].cubes()[1].count(), 4);
+    }
+
+    #[test]
+    fn overflow() {
+        let games = input_generator(
+            "Game 4294967295: 12 red\nGame 4294967295: 12 red\n\
+             Game 3: 4294967295 red, 4294967295 green, 4294967295 blue",
+        )
+        .unwrap();
+
+        assert_eq!(solve_part1(&games), 8589934590);
+        assert_eq!(games[0].power(), Some(0));
+        assert_eq!(games[2].power(), None);
+        assert!(matches!(solve_part2(&games), Err(SolveError::Overflow(_))));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            input_generator("Game 1: 3 blue\nGame 2: 4 purple").err(),
+            Some(ParseError::new(
+                2,
+                2,
+                11,
+                "a count of red, green or blue cubes"
+            ))
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use std::str::FromStr;
+
+use tracing::{debug, info};
+
+use crate::error::{ParseError, SolveError};
+use crate::grid::Grid;
+use crate::normalize::{normalize, Options};
+use crate::solution::{Answer, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawSchematic")
+)]
+pub struct Schematic {
+    grid: Grid<SchematicCell>,
+    numbers: Vec<u32>,
+}
+
+// A schematic as it is deserialized, before its cells are checked to point
+// at numbers that exist.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawSchematic {
+    grid: Grid<SchematicCell>,
+    numbers: Vec<u32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SchematicCell {
+    Empty,
+    // Index into `Schematic::numbers`, shared by every digit of the number.
+    Number(usize),
+    Symbol(char),
+}
+
+impl Schematic {
+    pub fn grid(&self) -> &Grid<SchematicCell> {
+        &self.grid
+    }
+
+    pub fn numbers(&self) -> &[u32] {
+        &self.numbers
+    }
+
+    // The number with a digit at `at`, if any.
+    pub fn number_at(&self, at: (usize, usize)) -> Option<u32> {
+        match self.grid.get(at)? {
+            SchematicCell::Number(n) => Some(self.numbers[*n]),
+            _ => None,
+        }
+    }
+
+    fn iter_symbols(&self) -> impl Iterator<Item = ((usize, usize), char)> + '_ {
+        self.grid.iter().filter_map(|(at, cell)| match cell {
+            SchematicCell::Symbol(s) => Some((at, *s)),
+            _ => None,
+        })
+    }
+
+    // Indices of the distinct numbers touching `at`.
+    fn adjacent_numbers(&self, at: (usize, usize)) -> Vec<usize> {
+        let mut nums = Vec::new();
+
+        for cell in self.grid.neighbours8(at).map(|p| self.grid[p]) {
+            if let SchematicCell::Number(n) = cell {
+                if !nums.contains(&n) {
+                    nums.push(n);
+                }
+            }
+        }
+
+        nums
+    }
+
+    // Whether each of `numbers` touches a symbol, making it a part number.
+    pub fn part_numbers(&self) -> Vec<bool> {
+        let mut adjacent = vec![false; self.numbers.len()];
+
+        for (at, _) in self.iter_symbols() {
+            for n in self.adjacent_numbers(at) {
+                adjacent[n] = true;
+            }
+        }
+
+        adjacent
+    }
+
+    // Each `*` touching exactly two numbers, with the indices of both.
+    pub fn gears(&self) -> impl Iterator<Item = ((usize, usize), [usize; 2])> + '_ {
+        self.iter_symbols()
+            .filter(|&(_, symbol)| symbol == '*')
+            .filter_map(|(at, _)| match self.adjacent_numbers(at)[..] {
+                [a, b] => Some((at, [a, b])),
+                _ => None,
+            })
+    }
+
+    // The product of the two numbers, by index, touching a gear.
+    pub fn gear_ratio(&self, [a, b]: [usize; 2]) -> u64 {
+        u64::from(self.numbers[a]) * u64::from(self.numbers[b])
+    }
+
+    fn iter_part_nums(&self) -> impl Iterator<Item = u64> + '_ {
+        self.numbers
+            .iter()
+            .zip(self.part_numbers())
+            .filter_map(|(&value, adjacent)| adjacent.then_some(value.into()))
+    }
+
+    fn iter_gears(&self) -> impl Iterator<Item = u64> + '_ {
+        self.gears().map(|(at, [a, b])| {
+            debug!(
+                x = at.0,
+                y = at.1,
+                a = self.numbers[a],
+                b = self.numbers[b],
+                "gear"
+            );
+            self.gear_ratio([a, b])
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawSchematic> for Schematic {
+    type Error = &'static str;
+
+    fn try_from(RawSchematic { grid, numbers }: RawSchematic) -> Result<Self, Self::Error> {
+        let dangling = grid
+            .iter()
+            .any(|(_, cell)| matches!(cell, SchematicCell::Number(n) if *n >= numbers.len()));
+
+        if dangling {
+            return Err("a number cell points past the end of the numbers");
+        }
+
+        Ok(Self { grid, numbers })
+    }
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        input_generator(s)
+    }
+}
+
+#[aoc_generator(day3)]
+pub fn input_generator(input: &str) -> Result<Schematic, ParseError> {
+    let (input, _) = normalize(input, Options::default());
+    let chars = Grid::parse(3, &input, Ok)?;
+    let mut grid = chars.map(|&ch| match ch {
+        '.' | '0'..='9' => SchematicCell::Empty,
+        s => SchematicCell::Symbol(s),
+    });
+    let mut numbers = Vec::new();
+
+    for (y, row) in chars.rows().enumerate() {
+        let mut x = 0;
+
+        while x < row.len() {
+            let len = row[x..].iter().take_while(|ch| ch.is_ascii_digit()).count();
+            if len == 0 {
+                x += 1;
+                continue;
+            }
+
+            let value = row[x..x + len]
+                .iter()
+                .collect::<String>()
+                .parse()
+                .map_err(|_| ParseError::new(3, y + 1, x + 1, "a number that fits in 32 bits"))?;
+
+            for x in x..x + len {
+                grid[(x, y)] = SchematicCell::Number(numbers.len());
+            }
+            numbers.push(value);
+            x += len;
+        }
+    }
+
+    let schematic = Schematic { grid, numbers };
+    info!(
+        width = schematic.grid.width(),
+        height = schematic.grid.height(),
+        numbers = schematic.numbers.len(),
+        symbols = schematic.iter_symbols().count(),
+        "parsed schematic"
+    );
+
+    Ok(schematic)
+}
+
+#[aoc(day3, part1)]
+pub fn solve_part1(input: &Schematic) -> u64 {
+    input.iter_part_nums().sum()
+}
+
+#[aoc(day3, part2)]
+pub fn solve_part2(input: &Schematic) -> Result<u64, SolveError> {
+    input
+        .iter_gears()
+        .try_fold(0u64, u64::checked_add)
+        .ok_or(SolveError::Overflow(
+            "the sum of gear ratios does not fit in 64 bits",
+        ))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    // A grid of dots, digits and symbols, every row as wide as the first.
+    fn looks_like(input: &str) -> bool {
+        let width = input.lines().next().map_or(0, str::len);
+
+        width > 0
+            && input.contains('.')
+            && input
+                .lines()
+                .all(|row| row.len() == width && !row.contains(char::is_whitespace))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Answer::try_from(solve_part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part2(input).and_then(Answer::try_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::examples;
+
+    examples!(Day03, "2023/day03": example);
+
+    #[test]
+    fn repeatable() {
+        let schematic = input_generator(example::INPUT).unwrap();
+
+        assert_eq!(solve_part1(&schematic), solve_part1(&schematic));
+    }
+
+    #[test]
+    fn overflow() {
+        let schematic = input_generator("99999*99999").unwrap();
+        assert_eq!(solve_part2(&schematic), Ok(9999800001));
+
+        let schematic = input_generator(
+            "4294967295*4294967295\n\
+             .....................\n\
+             4294967295*4294967295",
+        )
+        .unwrap();
+        assert_eq!(solve_part1(&schematic), 17179869180);
+        assert_eq!(
+            solve_part2(&schematic),
+            Err(SolveError::Overflow(
+                "the sum of gear ratios does not fit in 64 bits"
+            ))
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            input_generator("..*..........\n.99999999999.").err(),
+            Some(ParseError::new(3, 2, 2, "a number that fits in 32 bits"))
+        );
+        assert_eq!(
+            input_generator("..*..\n.12.").err(),
+            Some(ParseError::new(3, 2, 5, "a row of 5 cells"))
+        );
+    }
+}
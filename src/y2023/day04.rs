@@ -0,0 +1,204 @@
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    combinator::{consumed, map},
+    error::context,
+    sequence::tuple,
+};
+use tracing::{debug, info};
+
+use crate::error::{IResult, ParseError, SolveError};
+use crate::normalize::{normalize, Options};
+use crate::parse::{self, numbered, unsigned_list};
+use crate::solution::{Answer, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Game {
+    id: u32,
+    winning: Vec<u32>,
+    have: Vec<u32>,
+}
+
+impl Game {
+    pub fn new(id: u32, winning: Vec<u32>, have: Vec<u32>) -> Self {
+        Self { id, winning, have }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn winning(&self) -> &[u32] {
+        &self.winning
+    }
+
+    pub fn have(&self) -> &[u32] {
+        &self.have
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                numbered("Card"),
+                context("winning numbers", unsigned_list),
+                context("\" | \"", tag(" | ")),
+                context("numbers you have", unsigned_list),
+            )),
+            |(id, winning, _pipe, have)| Self { id, winning, have },
+        )(input)
+    }
+
+    // How many of the numbers you have are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.have
+            .iter()
+            .filter(|have| self.winning.iter().any(|win| win == *have))
+            .count()
+    }
+
+    // `None` if the score does not fit in 64 bits.
+    fn score(&self) -> Option<u64> {
+        match self.matches() {
+            0 => Some(0),
+            matches => 1u64.checked_shl(u32::try_from(matches - 1).ok()?),
+        }
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::all(4, s, Game::parse)
+    }
+}
+
+#[aoc_generator(day4)]
+pub fn input_generator(input: &str) -> Result<Vec<Game>, ParseError> {
+    let (input, _) = normalize(input, Options::default());
+    let games = parse::all(4, &input, parse::lines(consumed(Game::parse)))?
+        .into_iter()
+        .enumerate()
+        .map(|(i, (line, game))| {
+            if game.id as usize != i + 1 {
+                return Err(ParseError::at(4, &input, line, format!("card {}", i + 1)));
+            }
+
+            Ok(game)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    info!(cards = games.len(), "parsed cards");
+    Ok(games)
+}
+
+#[aoc(day4, part1)]
+pub fn solve_part1(input: &[Game]) -> Result<u64, SolveError> {
+    input
+        .iter()
+        .try_fold(0u64, |sum, game| sum.checked_add(game.score()?))
+        .ok_or(SolveError::Overflow(
+            "the total score does not fit in 64 bits",
+        ))
+}
+
+#[aoc(day4, part2)]
+pub fn solve_part2(input: &[Game]) -> Result<u64, SolveError> {
+    const OVERFLOW: SolveError = SolveError::Overflow("the card count does not fit in 64 bits");
+    let mut scores = vec![0u64; input.len() + 1];
+
+    // Cards are numbered by position rather than by `id`, which a caller
+    // building games by hand need not have kept in sequence.
+    for (i, game) in input.iter().enumerate().rev() {
+        let position = i + 1;
+        let mut score = 1u64;
+        let matches = game.matches();
+        let mut begin = position + 1;
+        let mut end = position + 1 + matches;
+
+        if begin > scores.len() {
+            begin = scores.len();
+        }
+
+        if end > scores.len() {
+            end = scores.len();
+        }
+
+        score = scores[begin..end]
+            .iter()
+            .try_fold(score, |sum, n| sum.checked_add(*n))
+            .ok_or(OVERFLOW)?;
+
+        debug!(
+            card = game.id,
+            matches,
+            cards = score,
+            "cards won, counting itself"
+        );
+        scores[position] = score;
+    }
+
+    scores
+        .iter()
+        .try_fold(0u64, |sum, n| sum.checked_add(*n))
+        .ok_or(OVERFLOW)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    fn looks_like(input: &str) -> bool {
+        input.starts_with("Card ")
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part1(input).and_then(Answer::try_from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part2(input).and_then(Answer::try_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::examples;
+
+    examples!(Day04, "2023/day04": example);
+
+    #[test]
+    fn overflow() {
+        let card = |id: usize, matches: usize| format!("Card {id}: 1 |{}", " 1".repeat(matches));
+
+        let games = input_generator(&card(1, 35)).unwrap();
+        assert_eq!(solve_part1(&games), Ok(1 << 34));
+
+        let cards: Vec<_> = (1..=70).map(|id| card(id, 70)).collect();
+        let games = input_generator(&cards.join("\n")).unwrap();
+        assert!(matches!(solve_part1(&games), Err(SolveError::Overflow(_))));
+        assert!(matches!(solve_part2(&games), Err(SolveError::Overflow(_))));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            input_generator("Card 1: 41 48 | 83 86\nCard 2: 13 32 61 30").err(),
+            Some(ParseError::new(4, 2, 20, "\" | \""))
+        );
+        assert_eq!(
+            input_generator("Card 1: 41 48 | 83 86\nCard 3: 13 32 | 61 30").err(),
+            Some(ParseError::new(4, 2, 1, "card 2"))
+        );
+    }
+}
@@ -0,0 +1,489 @@
+use std::ops::Range;
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::{newline, space1},
+    combinator::{map, map_opt},
+    error::context,
+    sequence::{preceded, separated_pair, terminated, tuple},
+};
+use tracing::{debug, info, trace, Level};
+
+use crate::context::Ticker;
+use crate::error::{IResult, ParseError, SolveError};
+use crate::normalize::{normalize, Options};
+use crate::parse::{self, blank_line, labelled, lines, sections, unsigned, unsigned_list};
+use crate::solution::{Answer, Part, Solution, Variant};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Almanac {
+    seeds: Seeds,
+    maps: Maps,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+struct Seeds(Vec<u32>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+struct Maps(Vec<Map>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Map(Vec<MapItem>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "RawMapItem", try_from = "RawMapItem")
+)]
+pub struct MapItem {
+    src: Range<u64>,
+    dst: Range<u64>,
+}
+
+// A map item in the same form as a line of the almanac.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RawMapItem {
+    dst: u32,
+    src: u32,
+    len: u32,
+}
+
+impl Almanac {
+    pub fn new(seeds: Vec<u32>, maps: Vec<Map>) -> Self {
+        Self {
+            seeds: Seeds(seeds),
+            maps: Maps(maps),
+        }
+    }
+
+    pub fn seeds(&self) -> &[u32] {
+        &self.seeds.0
+    }
+
+    pub fn maps(&self) -> &[Map] {
+        &self.maps.0
+    }
+
+    // Where `seed` ends up after going through every map in turn.
+    pub fn location(&self, seed: u32) -> u32 {
+        self.maps.location(seed)
+    }
+
+    // Every value `seed` takes on its way through the maps, starting with
+    // the seed itself and ending with its location.
+    pub fn path(&self, seed: u32) -> Vec<u32> {
+        let mut path = vec![seed];
+        path.extend(self.maps.path(seed));
+        path
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            separated_pair(Seeds::parse, blank_line, Maps::parse),
+            |(seeds, maps)| Self { seeds, maps },
+        )(input)
+    }
+
+    fn iter_locations(&self) -> impl Iterator<Item = u32> + '_ {
+        self.seeds.iter().map(|seed| {
+            if tracing::enabled!(Level::DEBUG) {
+                debug!(seed, path = ?self.maps.path(seed), "seed path");
+            }
+            self.maps.location(seed)
+        })
+    }
+
+    // No map reaches past `u32::MAX`, so a seed beyond it is its own
+    // location.
+    fn iter_locations2(&self) -> impl Iterator<Item = u64> + '_ {
+        self.seed_ranges().flat_map(|range| {
+            range.map(|seed| {
+                u32::try_from(seed).map_or(seed, |seed| self.maps.location(seed).into())
+            })
+        })
+    }
+
+    // Each start and length pair of seeds, in 64 bits since a range may run
+    // past `u32::MAX`.
+    pub fn seed_ranges(&self) -> impl Iterator<Item = Range<u64>> + '_ {
+        self.seeds.chunks(2).map(|range| {
+            let start = u64::from(range[0]);
+            start..start + u64::from(range[1])
+        })
+    }
+}
+
+impl Seeds {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map(labelled("seeds:", unsigned_list), Self)(input)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.0.iter().copied()
+    }
+
+    fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = &[u32]> {
+        self.0.chunks_exact(chunk_size)
+    }
+}
+
+impl Maps {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map(sections(Map::parse), Self)(input)
+    }
+
+    fn location(&self, seed: u32) -> u32 {
+        self.0.iter().fold(seed, |acc, map| map.location(acc))
+    }
+
+    // Every value `seed` takes on its way through the maps, ending with its
+    // location.
+    fn path(&self, seed: u32) -> Vec<u32> {
+        self.0
+            .iter()
+            .scan(seed, |acc, map| {
+                *acc = map.location(*acc);
+                Some(*acc)
+            })
+            .collect()
+    }
+
+    fn location_ranges(&self, seeds: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        self.0.iter().enumerate().fold(seeds, |acc, (i, map)| {
+            let ranges = map.location_ranges(acc);
+            trace!(map = i + 1, ranges = ranges.len(), "split seed ranges");
+            ranges
+        })
+    }
+}
+
+impl Map {
+    pub fn new(items: Vec<MapItem>) -> Self {
+        Self(items)
+    }
+
+    pub fn items(&self) -> &[MapItem] {
+        &self.0
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            preceded(
+                context("a map heading", Map::parse_heading),
+                lines(MapItem::parse),
+            ),
+            Self,
+        )(input)
+    }
+
+    fn parse_heading(input: &str) -> IResult<&str, &str> {
+        terminated(take_until(" "), tuple((space1, tag("map:"), newline)))(input)
+    }
+
+    fn location(&self, seed: u32) -> u32 {
+        for item in &self.0 {
+            if let Some(loc) = item.location(seed) {
+                return loc;
+            }
+        }
+
+        seed
+    }
+
+    // Splits each range at the edges of the map's items, so every piece
+    // maps to one contiguous destination range.
+    fn location_ranges(&self, mut unmapped: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        let mut mapped = Vec::new();
+
+        for item in &self.0 {
+            let (src, dst) = (item.src.clone(), item.dst.start);
+            let mut rest = Vec::new();
+
+            for range in unmapped {
+                let before = range.start..range.end.min(src.start);
+                let inside = range.start.max(src.start)..range.end.min(src.end);
+                let after = range.start.max(src.end)..range.end;
+
+                if !inside.is_empty() {
+                    let shift = |n: u64| n - src.start + dst;
+                    mapped.push(shift(inside.start)..shift(inside.end));
+                }
+
+                rest.extend([before, after].into_iter().filter(|r| !r.is_empty()));
+            }
+
+            unmapped = rest;
+        }
+
+        mapped.extend(unmapped);
+        mapped
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawMapItem> for MapItem {
+    type Error = &'static str;
+
+    fn try_from(RawMapItem { dst, src, len }: RawMapItem) -> Result<Self, Self::Error> {
+        Self::new(dst, src, len).ok_or("a map range must fit in 32 bits")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<MapItem> for RawMapItem {
+    fn from(item: MapItem) -> Self {
+        Self {
+            dst: item.dst.start as u32,
+            src: item.src.start as u32,
+            len: (item.src.end - item.src.start) as u32,
+        }
+    }
+}
+
+impl MapItem {
+    // `None` if either range would run past `u32::MAX`. The ranges are
+    // kept in 64 bits since one may end just after it.
+    pub fn new(dst: u32, src: u32, len: u32) -> Option<Self> {
+        const END: u64 = 1 << 32;
+        let (dst, src, len) = (u64::from(dst), u64::from(src), u64::from(len));
+
+        (src + len <= END && dst + len <= END).then(|| Self {
+            src: src..src + len,
+            dst: dst..dst + len,
+        })
+    }
+
+    pub fn src(&self) -> Range<u64> {
+        self.src.clone()
+    }
+
+    pub fn dst(&self) -> Range<u64> {
+        self.dst.clone()
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        context(
+            "a range that fits in 32 bits",
+            map_opt(
+                tuple((unsigned, space1, unsigned, space1, unsigned)),
+                |(dst, _, src, _, len)| Self::new(dst, src, len),
+            ),
+        )(input)
+    }
+
+    fn location(&self, seed: u32) -> Option<u32> {
+        let seed = u64::from(seed);
+        if self.src.contains(&seed) {
+            // Below `dst.end`, which is at most 2^32.
+            let offset = seed - self.src.start;
+            Some((self.dst.start + offset) as u32)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        input_generator(s)
+    }
+}
+
+#[aoc_generator(day5)]
+pub fn input_generator(input: &str) -> Result<Almanac, ParseError> {
+    let (input, _) = normalize(input, Options::default());
+    let almanac = parse::all(5, &input, Almanac::parse)?;
+
+    info!(
+        seeds = almanac.seeds.0.len(),
+        maps = almanac.maps.0.len(),
+        "parsed almanac"
+    );
+    Ok(almanac)
+}
+
+#[aoc(day5, part1)]
+pub fn solve_part1(input: &Almanac) -> u32 {
+    input.iter_locations().min().unwrap()
+}
+
+#[aoc(day5, part2)]
+pub fn solve_part2(input: &Almanac) -> Result<u64, SolveError> {
+    if !input.seeds.0.len().is_multiple_of(2) {
+        return Err(SolveError::NoSolution(
+            "seeds must come in start and length pairs",
+        ));
+    }
+
+    let total = input.seed_ranges().map(|r| r.end - r.start).sum();
+    let mut ticker = Ticker::new(Some(total));
+
+    input
+        .iter_locations2()
+        .try_fold(None, |min: Option<u64>, location| {
+            ticker.tick()?;
+            Ok(Some(min.map_or(location, |min| min.min(location))))
+        })?
+        .ok_or(SolveError::NoSolution("every seed range is empty"))
+}
+
+pub fn solve_part2_ranges(input: &Almanac) -> Result<u64, SolveError> {
+    if !input.seeds.0.len().is_multiple_of(2) {
+        return Err(SolveError::NoSolution(
+            "seeds must come in start and length pairs",
+        ));
+    }
+
+    input
+        .maps
+        .location_ranges(input.seed_ranges().collect())
+        .into_iter()
+        .filter(|r| !r.is_empty())
+        .map(|r| r.start)
+        .min()
+        .ok_or(SolveError::NoSolution("every seed range is empty"))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = Almanac;
+
+    const VARIANTS: &'static [Variant<Almanac>] = &[Variant {
+        name: "ranges",
+        part: Part::Two,
+        solve: |input| solve_part2_ranges(input).and_then(Answer::try_from),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    fn looks_like(input: &str) -> bool {
+        input.starts_with("seeds:")
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part2(input).and_then(Answer::try_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::Context;
+    use crate::fixtures::examples;
+    use std::time::Duration;
+
+    examples!(Day05, "2023/day05": example);
+
+    #[test]
+    fn times_out() {
+        let almanac: Almanac = example::INPUT
+            .replacen("79 14 55 13", "0 4000000000", 1)
+            .parse()
+            .unwrap();
+        let context = Context::new().with_timeout(Some(Duration::ZERO));
+
+        assert_eq!(
+            context.run(|| solve_part2(&almanac)),
+            Err(SolveError::TimedOut)
+        );
+        assert_eq!(solve_part2_ranges(&almanac), Ok(0));
+    }
+
+    #[test]
+    fn seed_range_past_u32() {
+        let almanac: Almanac = "seeds: 4294967295 2\n\nseed-to-soil map:\n0 4294967294 1"
+            .parse()
+            .unwrap();
+
+        assert_eq!(almanac.seed_ranges().next(), Some(4294967295..4294967297));
+        assert_eq!(solve_part2(&almanac), Ok(4294967295));
+        assert_eq!(solve_part2_ranges(&almanac), Ok(4294967295));
+
+        let almanac: Almanac =
+            "seeds: 4294967295 1 4294967290 2\n\nseed-to-soil map:\n7 4294967290 1"
+                .parse()
+                .unwrap();
+
+        assert_eq!(solve_part2(&almanac), Ok(7));
+        assert_eq!(solve_part2_ranges(&almanac), Ok(7));
+    }
+
+    #[test]
+    fn accessors() {
+        let almanac: Almanac = example::INPUT.parse().unwrap();
+
+        assert_eq!(almanac.seeds(), [79, 14, 55, 13]);
+        assert_eq!(almanac.maps().len(), 7);
+        assert_eq!(almanac.maps()[0].items()[0].src(), 98..100);
+        assert_eq!(almanac.maps()[0].items()[0].dst(), 50..52);
+        assert_eq!(almanac.location(79), 82);
+        assert_eq!(almanac.path(79), [79, 81, 81, 81, 74, 78, 78, 82]);
+        assert_eq!(MapItem::new(0, u32::MAX, 2), None);
+        assert_eq!(
+            MapItem::new(0, u32::MAX, 1).map(|item| item.src()),
+            Some(4294967295..4294967296)
+        );
+    }
+
+    #[test]
+    fn map_ending_at_u32_max() {
+        let almanac: Almanac =
+            "seeds: 4294967295 1 7 1\n\nseed-to-soil map:\n3 4294967295 1\n4294967295 7 1"
+                .parse()
+                .unwrap();
+
+        assert_eq!(almanac.location(4294967295), 3);
+        assert_eq!(almanac.location(7), 4294967295);
+        assert_eq!(solve_part2(&almanac), Ok(3));
+        assert_eq!(solve_part2_ranges(&almanac), Ok(3));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() {
+        let almanac: Almanac = example::INPUT.parse().unwrap();
+        let json = serde_json::to_string(&almanac).unwrap();
+
+        assert!(json.contains(r#"{"dst":50,"src":98,"len":2}"#), "{json}");
+        assert_eq!(serde_json::from_str::<Almanac>(&json).unwrap(), almanac);
+        assert!(serde_json::from_str::<MapItem>(r#"{"dst":0,"src":4294967295,"len":2}"#).is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            input_generator("seeds: 79 14\n\nseed-to-soil map:\n50 4294967290 10").err(),
+            Some(ParseError::new(5, 4, 1, "a range that fits in 32 bits"))
+        );
+    }
+}
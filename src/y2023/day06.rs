@@ -0,0 +1,260 @@
+use std::str::FromStr;
+
+use nom::{character::complete::newline, combinator::consumed, sequence::separated_pair};
+use tracing::{debug, info};
+
+use crate::context::Ticker;
+use crate::error::{ParseError, SolveError};
+use crate::normalize::{normalize, Options};
+use crate::parse::{self, labelled, unsigned_list};
+use crate::solution::{Answer, Part, Solution, Variant};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawInput")
+)]
+pub struct Input {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawInput {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+impl Input {
+    // `None` unless there is at least one race and a distance for each time.
+    pub fn new(times: Vec<u64>, distances: Vec<u64>) -> Option<Self> {
+        (!times.is_empty() && times.len() == distances.len()).then_some(Self { times, distances })
+    }
+
+    pub fn times(&self) -> &[u64] {
+        &self.times
+    }
+
+    pub fn distances(&self) -> &[u64] {
+        &self.distances
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawInput> for Input {
+    type Error = &'static str;
+
+    fn try_from(RawInput { times, distances }: RawInput) -> Result<Self, Self::Error> {
+        Self::new(times, distances)
+            .ok_or("there must be at least one race, each with a time and a distance")
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        input_generator(s)
+    }
+}
+
+#[aoc_generator(day6)]
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    let (input, _) = normalize(input, Options::default());
+    let (times, (distance_line, distances)) = parse::all(
+        6,
+        &input,
+        separated_pair(
+            labelled("Time:", unsigned_list),
+            newline,
+            consumed(labelled("Distance:", unsigned_list)),
+        ),
+    )?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::at(
+            6,
+            &input,
+            distance_line,
+            "one distance for each time",
+        ));
+    }
+
+    info!(races = times.len(), "parsed races");
+    Ok(Input { times, distances })
+}
+
+const OVERFLOW: SolveError =
+    SolveError::Overflow("the product of ways to win does not fit in 64 bits");
+
+fn concatenate(values: &[u64]) -> Result<u64, SolveError> {
+    values
+        .iter()
+        .map(|n| n.to_string())
+        .reduce(|mut acc, el| {
+            acc.push_str(&el);
+            acc
+        })
+        .unwrap()
+        .parse::<u64>()
+        .map_err(|_| SolveError::NoSolution("the single race does not fit in 64 bits"))
+}
+
+// The distance travelled rises until half way through the race and then
+// falls symmetrically, so the first winning hold time fixes the count.
+fn ways_to_win(time: u64, distance: u64) -> u64 {
+    let wins = |hold: u64| u128::from(hold) * u128::from(time - hold) > u128::from(distance);
+    let (mut lo, mut hi) = (0, time / 2 + 1);
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if wins(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    if lo > time / 2 {
+        0
+    } else {
+        time - 2 * lo + 1
+    }
+}
+
+#[aoc(day6, part1)]
+pub fn solve_part1(input: &Input) -> Result<u64, SolveError> {
+    input
+        .times
+        .iter()
+        .zip(input.distances.iter())
+        .map(|(t, d)| {
+            let ways = ways_to_win(*t, *d);
+            debug!(time = t, distance = d, ways, "race");
+            ways
+        })
+        .try_fold(1u64, u64::checked_mul)
+        .ok_or(OVERFLOW)
+}
+
+#[aoc(day6, part2)]
+pub fn solve_part2(input: &Input) -> Result<u64, SolveError> {
+    Ok(ways_to_win(
+        concatenate(&input.times)?,
+        concatenate(&input.distances)?,
+    ))
+}
+
+// Tries every hold time, so a race as long as part 2's can take minutes.
+pub fn solve_part1_brute(input: &Input) -> Result<u64, SolveError> {
+    let total = input
+        .times
+        .iter()
+        .fold(0, |total: u64, t| total.saturating_add(t.saturating_add(1)));
+    let mut ticker = Ticker::new(Some(total));
+
+    input
+        .times
+        .iter()
+        .zip(input.distances.iter())
+        .try_fold(1u64, |product, (t, d)| {
+            let ways = (0..=*t).try_fold(0u64, |ways, hold| {
+                ticker.tick()?;
+                let wins = u128::from(hold) * u128::from(t - hold) > u128::from(*d);
+                Ok::<_, SolveError>(ways + u64::from(wins))
+            })?;
+            debug!(time = t, distance = d, ways, "race");
+            product.checked_mul(ways).ok_or(OVERFLOW)
+        })
+}
+
+pub fn solve_part2_brute(input: &Input) -> Result<u64, SolveError> {
+    let time = concatenate(&input.times)?;
+    let distance = concatenate(&input.distances)?;
+
+    solve_part1_brute(&Input {
+        times: vec![time],
+        distances: vec![distance],
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Input = Input;
+
+    const VARIANTS: &'static [Variant<Input>] = &[
+        Variant {
+            name: "brute",
+            part: Part::One,
+            solve: |input| solve_part1_brute(input).and_then(Answer::try_from),
+        },
+        Variant {
+            name: "brute",
+            part: Part::Two,
+            solve: |input| solve_part2_brute(input).and_then(Answer::try_from),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    fn looks_like(input: &str) -> bool {
+        input.starts_with("Time:")
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part1(input).and_then(Answer::try_from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part2(input).and_then(Answer::try_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::Context;
+    use crate::fixtures::examples;
+    use std::time::Duration;
+
+    examples!(Day06, "2023/day06": example);
+
+    #[test]
+    fn overflow() {
+        let races =
+            input_generator("Time:      100000 100000 100000 100000\nDistance:  0 0 0 0").unwrap();
+
+        assert_eq!(solve_part1(&races), Err(OVERFLOW));
+        assert_eq!(solve_part1_brute(&races), Err(OVERFLOW));
+
+        let race = Input::new(vec![u64::MAX], vec![u64::MAX - 1]).unwrap();
+        assert_eq!(solve_part1(&race), Ok(u64::MAX - 3));
+    }
+
+    #[test]
+    fn times_out() {
+        let race = input_generator("Time: 4294967295\nDistance: 0").unwrap();
+        let context = Context::new().with_timeout(Some(Duration::ZERO));
+
+        assert_eq!(solve_part1(&race), Ok(4294967294));
+        assert_eq!(
+            context.run(|| solve_part1_brute(&race)),
+            Err(SolveError::TimedOut)
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            input_generator("Time:      7  15   30\nDistance:  9  4O  200").err(),
+            Some(ParseError::new(6, 2, 15, "a number"))
+        );
+    }
+}
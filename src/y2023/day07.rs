@@ -0,0 +1,496 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::map,
+    error::context,
+    sequence::{separated_pair, tuple},
+};
+use tracing::{debug, info};
+
+use crate::error::{IResult, ParseError, SolveError};
+use crate::normalize::{normalize, Options};
+use crate::parse::{self, unsigned};
+use crate::solution::{Answer, Part, Solution, Variant};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "RawHand")
+)]
+pub struct Hand {
+    cards: Vec<Card>,
+    bid: u32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    score: Option<Score>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawHand {
+    cards: [Card; 5],
+    bid: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Score {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[cfg(feature = "serde")]
+impl From<RawHand> for Hand {
+    fn from(RawHand { cards, bid }: RawHand) -> Self {
+        Self::new(cards, bid)
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.score.cmp(&other.score) {
+            Ordering::Equal => self.cards.cmp(&other.cards),
+            other => other,
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hand {
+    pub fn new(cards: [Card; 5], bid: u32) -> Self {
+        Self {
+            cards: cards.to_vec(),
+            bid,
+            score: None,
+        }
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn bid(&self) -> u32 {
+        self.bid
+    }
+
+    // The hand's type with every card, jokers included, taken at face value.
+    pub fn score(&self) -> Score {
+        self.clone().update_score()
+    }
+
+    // The hand's type with its jacks played as jokers, as in part 2.
+    pub fn joker_score(&self) -> Score {
+        let mut hand = self.clone();
+        hand.jacks_to_jokers();
+        hand.upgrade();
+        hand.score.unwrap()
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            separated_pair(
+                context(
+                    "five cards",
+                    tuple((
+                        Card::parse,
+                        Card::parse,
+                        Card::parse,
+                        Card::parse,
+                        Card::parse,
+                    )),
+                ),
+                space1,
+                context("a bid", unsigned),
+            ),
+            |((first, second, third, fourth, fifth), bid)| Self {
+                cards: vec![first, second, third, fourth, fifth],
+                score: None,
+                bid,
+            },
+        )(input)
+    }
+
+    fn update_score(&mut self) -> Score {
+        type ScoreFn = for<'a, 'b> fn(&'a Hand, &'b [(Card, usize)]) -> Option<Score>;
+
+        const SCORE_FNS: [ScoreFn; 7] = [
+            Hand::five_of_a_kind,
+            Hand::four_of_a_kind,
+            Hand::full_house,
+            Hand::three_of_a_kind,
+            Hand::two_pair,
+            Hand::one_pair,
+            Hand::high_card,
+        ];
+
+        let counts = self.card_counts();
+
+        self.score = SCORE_FNS.iter().find_map(|f| f(self, &counts));
+        self.score.unwrap()
+    }
+
+    fn five_of_a_kind(&self, counts: &[(Card, usize)]) -> Option<Score> {
+        counts
+            .iter()
+            .find(|(_, n)| *n == 5)
+            .and(Some(Score::FiveOfAKind))
+    }
+
+    fn four_of_a_kind(&self, counts: &[(Card, usize)]) -> Option<Score> {
+        counts
+            .iter()
+            .find(|(_, n)| *n == 4)
+            .and(Some(Score::FourOfAKind))
+    }
+
+    fn full_house(&self, counts: &[(Card, usize)]) -> Option<Score> {
+        let three = counts.iter().find(|(_, n)| *n == 3);
+        let two = counts.iter().find(|(_, n)| *n == 2);
+
+        (three.is_some() && two.is_some()).then_some(Score::FullHouse)
+    }
+
+    fn three_of_a_kind(&self, counts: &[(Card, usize)]) -> Option<Score> {
+        counts
+            .iter()
+            .find(|(_, n)| *n == 3)
+            .and(Some(Score::ThreeOfAKind))
+    }
+
+    fn two_pair(&self, counts: &[(Card, usize)]) -> Option<Score> {
+        let mut owned = counts.to_owned();
+
+        owned.retain(|&(_, n)| n == 2);
+
+        (owned.len() == 2).then_some(Score::TwoPair)
+    }
+
+    fn one_pair(&self, counts: &[(Card, usize)]) -> Option<Score> {
+        counts
+            .iter()
+            .find(|(_, n)| *n == 2)
+            .and(Some(Score::OnePair))
+    }
+
+    fn high_card(&self, _counts: &[(Card, usize)]) -> Option<Score> {
+        Some(Score::HighCard)
+    }
+
+    fn card_counts(&self) -> Vec<(Card, usize)> {
+        let mut map = BTreeMap::new();
+
+        self.cards
+            .iter()
+            .for_each(|card| *map.entry(*card).or_default() += 1);
+
+        map.into_iter().collect()
+    }
+
+    fn jacks_to_jokers(&mut self) {
+        for card in &mut self.cards {
+            if *card == Card::Jack {
+                *card = Card::Joker;
+            }
+        }
+    }
+
+    // Scores the hand as the best of every way of replacing all its jokers
+    // with one other card.
+    fn best_substitution(&mut self) {
+        const SUBSTITUTES: [Card; 13] = [
+            Card::Two,
+            Card::Three,
+            Card::Four,
+            Card::Five,
+            Card::Six,
+            Card::Seven,
+            Card::Eight,
+            Card::Nine,
+            Card::Ten,
+            Card::Jack,
+            Card::Queen,
+            Card::King,
+            Card::Ace,
+        ];
+
+        self.score = SUBSTITUTES
+            .iter()
+            .map(|&substitute| {
+                let mut hand = self.clone();
+                hand.cards
+                    .iter_mut()
+                    .filter(|card| **card == Card::Joker)
+                    .for_each(|card| *card = substitute);
+                hand.update_score()
+            })
+            .max();
+    }
+
+    fn upgrade(&mut self) {
+        let orig_cards = self.cards.clone();
+        self.cards.retain(|card| *card != Card::Joker);
+
+        let n_jokers = 5 - self.cards.len();
+
+        if n_jokers == 5 {
+            debug!(cards = ?orig_cards, to = ?Score::FiveOfAKind, "joker upgrade");
+            self.score = Some(Score::FiveOfAKind);
+            self.cards = orig_cards;
+            return;
+        }
+
+        let jokerless_score = self.update_score();
+
+        if n_jokers == 0 {
+            return;
+        }
+
+        let new_score = match jokerless_score {
+            Score::HighCard => match n_jokers {
+                1 => Score::OnePair,
+                2 => Score::ThreeOfAKind,
+                3 => Score::FourOfAKind,
+                4 => Score::FiveOfAKind,
+                _ => unreachable!(),
+            },
+            Score::OnePair => match n_jokers {
+                1 => Score::ThreeOfAKind,
+                2 => Score::FourOfAKind,
+                3 => Score::FiveOfAKind,
+                _ => unreachable!(),
+            },
+            Score::TwoPair => Score::FullHouse,
+            Score::ThreeOfAKind => match n_jokers {
+                1 => Score::FourOfAKind,
+                2 => Score::FiveOfAKind,
+                _ => unreachable!(),
+            },
+            Score::FullHouse => {
+                unreachable!()
+            }
+            Score::FourOfAKind => match n_jokers {
+                1 => Score::FiveOfAKind,
+                _ => unreachable!(),
+            },
+            Score::FiveOfAKind => {
+                unreachable!()
+            }
+        };
+
+        debug!(
+            cards = ?orig_cards,
+            jokers = n_jokers,
+            from = ?jokerless_score,
+            to = ?new_score,
+            "joker upgrade"
+        );
+        self.score = Some(new_score);
+        self.cards = orig_cards;
+    }
+}
+
+impl Card {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(tag("A"), |_| Self::Ace),
+            map(tag("K"), |_| Self::King),
+            map(tag("Q"), |_| Self::Queen),
+            map(tag("J"), |_| Self::Jack),
+            map(tag("T"), |_| Self::Ten),
+            map(tag("9"), |_| Self::Nine),
+            map(tag("8"), |_| Self::Eight),
+            map(tag("7"), |_| Self::Seven),
+            map(tag("6"), |_| Self::Six),
+            map(tag("5"), |_| Self::Five),
+            map(tag("4"), |_| Self::Four),
+            map(tag("3"), |_| Self::Three),
+            map(tag("2"), |_| Self::Two),
+        ))(input)
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::all(7, s, Hand::parse)
+    }
+}
+
+impl TryFrom<char> for Card {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        parse::all(
+            7,
+            c.encode_utf8(&mut [0; 4]),
+            context("a card", Card::parse),
+        )
+    }
+}
+
+#[aoc_generator(day7)]
+pub fn input_generator(input: &str) -> Result<Vec<Hand>, ParseError> {
+    let (input, _) = normalize(input, Options::default());
+    let hands = parse::all(7, &input, parse::lines(Hand::parse))?;
+
+    info!(hands = hands.len(), "parsed hands");
+    Ok(hands)
+}
+
+fn winnings(mut hands: Vec<Hand>) -> Result<u64, SolveError> {
+    hands.sort();
+
+    hands
+        .iter()
+        .zip(1u64..)
+        .try_fold(0u64, |sum, (hand, rank)| {
+            sum.checked_add(u64::from(hand.bid).checked_mul(rank)?)
+        })
+        .ok_or(SolveError::Overflow(
+            "the total winnings do not fit in 64 bits",
+        ))
+}
+
+#[aoc(day7, part1)]
+pub fn solve_part1(input: &[Hand]) -> Result<u64, SolveError> {
+    let mut input = input.to_vec();
+    input.iter_mut().for_each(|hand| {
+        hand.update_score();
+    });
+
+    winnings(input)
+}
+
+#[aoc(day7, part2)]
+pub fn solve_part2(input: &[Hand]) -> Result<u64, SolveError> {
+    let mut input = input.to_vec();
+
+    input.iter_mut().for_each(|hand| {
+        hand.jacks_to_jokers();
+        hand.upgrade();
+    });
+
+    winnings(input)
+}
+
+pub fn solve_part2_brute(input: &[Hand]) -> Result<u64, SolveError> {
+    let mut input = input.to_vec();
+
+    input.iter_mut().for_each(|hand| {
+        hand.jacks_to_jokers();
+        hand.best_substitution();
+    });
+
+    winnings(input)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Input = Vec<Hand>;
+
+    const VARIANTS: &'static [Variant<Vec<Hand>>] = &[Variant {
+        name: "brute",
+        part: Part::Two,
+        solve: |input| solve_part2_brute(input).and_then(Answer::try_from),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    // Five cards and a bid on every line.
+    fn looks_like(input: &str) -> bool {
+        !input.is_empty()
+            && input.lines().all(|line| {
+                line.split_once(' ').is_some_and(|(cards, bid)| {
+                    cards.chars().count() == 5
+                        && cards.chars().all(|c| Card::try_from(c).is_ok())
+                        && bid.parse::<u32>().is_ok()
+                })
+            })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part1(input).and_then(Answer::try_from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part2(input).and_then(Answer::try_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::examples;
+
+    examples!(Day07, "2023/day07": example);
+
+    #[test]
+    fn accessors() {
+        let hand: Hand = "KTJJT 220".parse().unwrap();
+
+        assert_eq!(hand.bid(), 220);
+        assert_eq!(hand.cards()[..2], [Card::King, Card::Ten]);
+        assert_eq!(hand.score(), Score::TwoPair);
+        assert_eq!(hand.joker_score(), Score::FourOfAKind);
+        assert_eq!(Card::try_from('J'), Ok(Card::Jack));
+        assert!(Card::try_from('X').is_err());
+    }
+
+    #[test]
+    fn overflow() {
+        let hands = input_generator("AAAAA 4294967295\nKKKKK 4294967295").unwrap();
+        assert_eq!(solve_part1(&hands), Ok(12884901885));
+
+        let hands = vec![Hand::new([Card::Ace; 5], u32::MAX); 100_000];
+        assert!(matches!(solve_part1(&hands), Err(SolveError::Overflow(_))));
+        assert!(matches!(solve_part2(&hands), Err(SolveError::Overflow(_))));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            input_generator("32T3K 765\nT55X5 684").err(),
+            Some(ParseError::new(7, 2, 4, "five cards"))
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::alphanumeric1,
+    combinator::map,
+    error::{context, VerboseError, VerboseErrorKind},
+    multi::many1,
+    sequence::separated_pair,
+};
+use tracing::{debug, info};
+
+use crate::context::Ticker;
+use crate::error::{IResult, ParseError, SolveError};
+use crate::normalize::{normalize, Options};
+use crate::parse::{self, blank_line, key_value, lines, tuple2};
+use crate::solution::{Answer, Part, Solution, Variant};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawDocuments")
+)]
+pub struct Documents {
+    instructions: Vec<Instruction>,
+    network: Network,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawDocuments {
+    instructions: Vec<Instruction>,
+    network: Network,
+}
+
+struct DocumentIter<'a> {
+    documents: &'a Documents,
+    iter: Box<dyn Iterator<Item = Instruction> + 'a>,
+    next: &'a String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "HashMap<String, (String, String)>",
+        try_from = "HashMap<String, (String, String)>"
+    )
+)]
+pub struct Network(HashMap<String, (String, String)>);
+
+impl Documents {
+    // `None` without any instructions to follow.
+    pub fn new(instructions: Vec<Instruction>, network: Network) -> Option<Self> {
+        (!instructions.is_empty()).then_some(Self {
+            instructions,
+            network,
+        })
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn network(&self) -> &Network {
+        &self.network
+    }
+
+    // The endless walk from `start`, beginning with `start` itself.
+    pub fn route(&self, start: &str) -> Option<impl Iterator<Item = &str> + '_> {
+        Some(self.iter(start)?.map(String::as_str))
+    }
+
+    // Every node visited walking from `start` until `done`, both ends
+    // included; `None` if the walk never gets there.
+    pub fn walk(&self, start: &str, done: impl Fn(&str) -> bool) -> Option<Vec<&str>> {
+        let limit = self.network.0.len() * self.instructions.len();
+        let mut path = Vec::new();
+
+        for node in self.route(start)?.take(limit + 1) {
+            path.push(node);
+            if done(node) {
+                return Some(path);
+            }
+        }
+
+        None
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            separated_pair(
+                context("L/R instructions", many1(Instruction::parse)),
+                blank_line,
+                Network::parse,
+            ),
+            |(instructions, network)| Self {
+                instructions,
+                network,
+            },
+        )(input)
+    }
+
+    fn iter(&self, start: &str) -> Option<DocumentIter<'_>> {
+        Some(DocumentIter {
+            documents: self,
+            iter: Box::new(self.instructions.iter().copied().cycle()),
+            next: self.network.0.get_key_value(start)?.0,
+        })
+    }
+
+    // A walk that has not arrived after visiting every (node, instruction)
+    // state is going round a cycle that never will.
+    fn steps_until(&self, start: &str, done: impl Fn(&str) -> bool) -> Result<usize, SolveError> {
+        let limit = self.network.0.len() * self.instructions.len();
+        let mut ticker = Ticker::new(Some(limit as u64 + 1));
+
+        let walk = self.iter(start).ok_or(SolveError::NoSolution(
+            "the start node is not in the network",
+        ))?;
+        for (steps, node) in walk.take(limit + 1).enumerate() {
+            if done(node) {
+                return Ok(steps);
+            }
+            ticker.tick()?;
+        }
+
+        Err(SolveError::NoSolution(
+            "a walk never reaches its destination",
+        ))
+    }
+
+    fn step(&self, node: &String, instruction: Instruction) -> Option<&String> {
+        self.network.step(node, instruction)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawDocuments> for Documents {
+    type Error = &'static str;
+
+    fn try_from(
+        RawDocuments {
+            instructions,
+            network,
+        }: RawDocuments,
+    ) -> Result<Self, Self::Error> {
+        Self::new(instructions, network).ok_or("there must be at least one instruction")
+    }
+}
+
+impl<'a> Iterator for DocumentIter<'a> {
+    type Item = &'a String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.next;
+
+        self.next = self.documents.step(res, self.iter.next().unwrap()).unwrap();
+
+        Some(res)
+    }
+}
+
+impl Instruction {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(tag("L"), |_| Self::Left),
+            map(tag("R"), |_| Self::Right),
+        ))(input)
+    }
+}
+
+impl TryFrom<HashMap<String, (String, String)>> for Network {
+    type Error = &'static str;
+
+    fn try_from(nodes: HashMap<String, (String, String)>) -> Result<Self, Self::Error> {
+        Self::new(nodes).ok_or("every destination must be a node in the network")
+    }
+}
+
+impl From<Network> for HashMap<String, (String, String)> {
+    fn from(network: Network) -> Self {
+        network.0
+    }
+}
+
+impl Network {
+    // `None` if a node leads somewhere that is not itself a node.
+    pub fn new(nodes: impl IntoIterator<Item = (String, (String, String))>) -> Option<Self> {
+        let network = Self(nodes.into_iter().collect());
+
+        network
+            .0
+            .values()
+            .all(|(left, right)| network.0.contains_key(left) && network.0.contains_key(right))
+            .then_some(network)
+    }
+
+    // The left and right destinations of `node`.
+    pub fn get(&self, node: &str) -> Option<(&str, &str)> {
+        self.0
+            .get(node)
+            .map(|(left, right)| (left.as_str(), right.as_str()))
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    fn parse(input: &str) -> IResult<&str, Self> {
+        let (rest, mappings) = lines(key_value(
+            Network::parse_nodename,
+            "=",
+            context(
+                "a destination like (AAA, BBB)",
+                tuple2(Network::parse_nodename),
+            ),
+        ))(input)?;
+
+        for (_, (left, right)) in &mappings {
+            for dest in [left, right] {
+                if !mappings.iter().any(|(src, _)| src == dest) {
+                    return Err(nom::Err::Failure(VerboseError {
+                        errors: vec![(
+                            dest,
+                            VerboseErrorKind::Context("a node defined in the network"),
+                        )],
+                    }));
+                }
+            }
+        }
+
+        Ok((
+            rest,
+            Self(
+                mappings
+                    .into_iter()
+                    .map(|(src, (left, right))| {
+                        (src.to_string(), (left.to_string(), right.to_string()))
+                    })
+                    .collect(),
+            ),
+        ))
+    }
+
+    fn parse_nodename(input: &str) -> IResult<&str, &str> {
+        alphanumeric1(input)
+    }
+
+    fn step(&self, node: &String, instruction: Instruction) -> Option<&String> {
+        let (left, right) = self.0.get(node)?;
+
+        match instruction {
+            Instruction::Left => Some(left),
+            Instruction::Right => Some(right),
+        }
+    }
+}
+
+impl FromStr for Documents {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        input_generator(s)
+    }
+}
+
+#[aoc_generator(day8)]
+pub fn input_generator(input: &str) -> Result<Documents, ParseError> {
+    let (input, _) = normalize(input, Options::default());
+    let documents = parse::all(8, &input, Documents::parse)?;
+
+    info!(
+        instructions = documents.instructions.len(),
+        nodes = documents.network.0.len(),
+        "parsed documents"
+    );
+    Ok(documents)
+}
+
+#[aoc(day8, part1)]
+pub fn solve_part1(input: &Documents) -> Result<usize, SolveError> {
+    input.steps_until("AAA", |node| node == "ZZZ")
+}
+
+#[aoc(day8, part2)]
+pub fn solve_part2(input: &Documents) -> Result<usize, SolveError> {
+    let a_keys = input.network.0.keys().filter(|key| key.ends_with('A'));
+
+    let results = a_keys
+        .map(|k| {
+            let steps = input.steps_until(k, |s| s.ends_with('Z'));
+            debug!(ghost = k, steps = ?steps, "steps to a Z node");
+            steps
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if results.is_empty() {
+        return Err(SolveError::NoSolution("no node ends in A"));
+    }
+
+    results
+        .into_iter()
+        .try_fold(1usize, |acc, i| {
+            (acc / num::integer::gcd(acc, i)).checked_mul(i)
+        })
+        .ok_or(SolveError::Overflow(
+            "the step count does not fit in a usize",
+        ))
+}
+
+// Walks every ghost in lock-step instead of assuming each one loops back to
+// its first `Z` node. Exact for any network, but only practical when the
+// answer is small.
+pub fn solve_part2_simulate(input: &Documents) -> Result<usize, SolveError> {
+    const STEP_LIMIT: usize = 10_000_000;
+
+    let mut nodes: Vec<&String> = input
+        .network
+        .0
+        .keys()
+        .filter(|key| key.ends_with('A'))
+        .collect();
+
+    if nodes.is_empty() {
+        return Err(SolveError::NoSolution("no node ends in A"));
+    }
+
+    let mut ticker = Ticker::new(Some(STEP_LIMIT as u64));
+
+    for (steps, &instruction) in input
+        .instructions
+        .iter()
+        .cycle()
+        .enumerate()
+        .take(STEP_LIMIT)
+    {
+        if nodes.iter().all(|node| node.ends_with('Z')) {
+            return Ok(steps);
+        }
+        ticker.tick()?;
+
+        for node in nodes.iter_mut() {
+            *node = input
+                .step(node, instruction)
+                .expect("checked by input_generator");
+        }
+    }
+
+    Err(SolveError::TooExpensive(
+        "the ghosts did not meet within the step limit",
+    ))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    type Input = Documents;
+
+    const VARIANTS: &'static [Variant<Documents>] = &[Variant {
+        name: "simulate",
+        part: Part::Two,
+        solve: |input| solve_part2_simulate(input).and_then(Answer::try_from),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    // A line of `L`s and `R`s, then nodes with a left and a right.
+    fn looks_like(input: &str) -> bool {
+        input
+            .lines()
+            .next()
+            .is_some_and(|line| !line.is_empty() && line.bytes().all(|b| b == b'L' || b == b'R'))
+            && input.contains(" = (")
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part1(input).and_then(Answer::try_from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part2(input).and_then(Answer::try_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::examples;
+
+    examples!(Day08, "2023/day08": example1, example2, example3);
+
+    #[test]
+    fn accessors() {
+        let documents: Documents = example2::INPUT.parse().unwrap();
+
+        assert_eq!(
+            documents.instructions(),
+            [Instruction::Left, Instruction::Left, Instruction::Right]
+        );
+        assert_eq!(documents.network().get("BBB"), Some(("AAA", "ZZZ")));
+        assert_eq!(documents.network().nodes().count(), 3);
+        assert_eq!(
+            documents.walk("AAA", |node| node == "ZZZ"),
+            Some(vec!["AAA", "BBB", "AAA", "BBB", "AAA", "BBB", "ZZZ"])
+        );
+        assert_eq!(documents.walk("ZZZ", |node| node == "AAA"), None);
+
+        let dangling = [("AAA".to_string(), ("AAA".to_string(), "ZZZ".to_string()))];
+        assert_eq!(Network::new(dangling), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json() {
+        let documents: Documents = example2::INPUT.parse().unwrap();
+        let json = serde_json::to_string(&documents).unwrap();
+
+        assert_eq!(serde_json::from_str::<Documents>(&json).unwrap(), documents);
+        assert!(serde_json::from_str::<Network>(r#"{"AAA":["AAA","BBB"]}"#).is_err());
+        assert!(serde_json::from_str::<Documents>(r#"{"instructions":[],"network":{}}"#).is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            input_generator("RL\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, CCC)\nZZZ = (ZZZ, ZZZ)").err(),
+            Some(ParseError::new(8, 4, 13, "a node defined in the network"))
+        );
+    }
+
+    #[test]
+    fn unsolvable() {
+        let stuck = input_generator("L\n\nAAA = (BBA, ZZZ)\nBBA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)");
+
+        assert_eq!(
+            solve_part1(&stuck.unwrap()),
+            Err(SolveError::NoSolution(
+                "a walk never reaches its destination"
+            ))
+        );
+        assert_eq!(
+            solve_part1(&input_generator(example3::INPUT).unwrap()),
+            Err(SolveError::NoSolution(
+                "the start node is not in the network"
+            ))
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use tracing::{debug, info};
+
+use crate::error::{ParseError, SolveError};
+use crate::normalize::{normalize, Options};
+use crate::parse::{self, signed_list};
+use crate::solution::{Answer, Solution};
+
+#[aoc_generator(day9)]
+pub fn input_generator(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    let (input, _) = normalize(input, Options::default());
+    let histories = parse::all(9, &input, parse::lines(signed_list))?;
+
+    info!(histories = histories.len(), "parsed histories");
+    Ok(histories)
+}
+
+// The history followed by each row of differences, down to the first row
+// that is all zeroes, or `None` if a difference does not fit in 64 bits.
+pub fn differences(values: &[i32]) -> Option<Vec<Vec<i64>>> {
+    let mut rows = vec![values.iter().map(|n| i64::from(*n)).collect::<Vec<_>>()];
+
+    while rows.last().is_some_and(|row| row.iter().any(|n| *n != 0)) {
+        let row = rows.last().unwrap();
+        let next = row
+            .windows(2)
+            .map(|pairs| pairs[1].checked_sub(pairs[0]))
+            .collect::<Option<_>>()?;
+        rows.push(next);
+    }
+
+    Some(rows)
+}
+
+// `None` if a difference or the extrapolated value does not fit in 64 bits.
+pub fn next(values: &[i32]) -> Option<i64> {
+    differences(values)?
+        .iter()
+        .filter_map(|row| row.last())
+        .try_fold(0i64, |acc, n| acc.checked_add(*n))
+}
+
+// `None` if a difference or the extrapolated value does not fit in 64 bits.
+pub fn prev(values: &[i32]) -> Option<i64> {
+    differences(values)?
+        .iter()
+        .rev()
+        .filter_map(|row| row.first())
+        .try_fold(0i64, |acc, n| n.checked_sub(acc))
+}
+
+const OVERFLOW: SolveError = SolveError::Overflow("an extrapolated value does not fit in 64 bits");
+
+#[aoc(day9, part1)]
+pub fn solve_part1(input: &[Vec<i32>]) -> Result<i64, SolveError> {
+    input.iter().try_fold(0i64, |sum, i| {
+        let next = next(i).ok_or(OVERFLOW)?;
+        debug!(history = ?i, next, "extrapolated forwards");
+        sum.checked_add(next).ok_or(OVERFLOW)
+    })
+}
+
+#[aoc(day9, part2)]
+pub fn solve_part2(input: &[Vec<i32>]) -> Result<i64, SolveError> {
+    input.iter().try_fold(0i64, |sum, i| {
+        let prev = prev(i).ok_or(OVERFLOW)?;
+        debug!(history = ?i, prev, "extrapolated backwards");
+        sum.checked_add(prev).ok_or(OVERFLOW)
+    })
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+
+    // Lines of whole numbers separated by spaces.
+    fn looks_like(input: &str) -> bool {
+        !input.is_empty()
+            && input
+                .lines()
+                .all(|line| line.split(' ').all(|n| n.parse::<i64>().is_ok()))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::examples;
+
+    examples!(Day09, "2023/day09": example);
+
+    #[test]
+    fn extrapolates_backwards() {
+        assert_eq!(prev(&[10, 13, 16, 21, 30, 45]), Some(5));
+    }
+
+    #[test]
+    fn difference_rows() {
+        assert_eq!(
+            differences(&[1, 3, 6, 10]),
+            Some(vec![vec![1, 3, 6, 10], vec![2, 3, 4], vec![1, 1], vec![0]])
+        );
+    }
+
+    // What `cargo aoc` hands over is the raw file, not a normalized input.
+    #[test]
+    fn windows_input() {
+        let histories = input_generator("\u{feff}0 3 6 9 12 15\r\n1 3 6 10 15 21\r\n").unwrap();
+
+        assert_eq!(histories, [[0, 3, 6, 9, 12, 15], [1, 3, 6, 10, 15, 21]]);
+    }
+
+    #[test]
+    fn overflow() {
+        let histories = input_generator("2147483647 -2147483648").unwrap();
+        assert_eq!(solve_part1(&histories), Ok(-6442450943));
+        assert_eq!(solve_part2(&histories), Ok(6442450942));
+
+        let history: Vec<_> = [i32::MIN, i32::MAX].into_iter().cycle().take(70).collect();
+        assert_eq!(next(&history), None);
+        assert_eq!(
+            solve_part1(&[history]),
+            Err(SolveError::Overflow(
+                "an extrapolated value does not fit in 64 bits"
+            ))
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            input_generator("0 3 6 9 12 15\n1 3 six 10 15 21").err(),
+            Some(ParseError::new(9, 2, 5, "a number"))
+        );
+    }
+}
@@ -0,0 +1,64 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc2023.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                      \
+    do {                                                                 \
+        if (!(cond)) {                                                   \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,       \
+                    __LINE__, #cond);                                    \
+            failures++;                                                  \
+        }                                                                \
+    } while (0)
+
+static Aoc2023Status solve(uint32_t day, uint32_t part, const char *input,
+                           char *out, size_t out_len) {
+    return aoc2023_solve(day, part, (const uint8_t *)input, strlen(input),
+                         (uint8_t *)out, out_len);
+}
+
+static const char *last_error(void) {
+    static char message[256];
+    aoc2023_last_error((uint8_t *)message, sizeof message);
+    return message;
+}
+
+int main(void) {
+    char out[32];
+
+    CHECK(solve(9, 1, "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n",
+                out, sizeof out) == AOC2023_STATUS_OK);
+    CHECK(strcmp(out, "114") == 0);
+
+    CHECK(solve(6, 2, "Time:      7  15   30\nDistance:  9  40  200\n", out,
+                sizeof out) == AOC2023_STATUS_OK);
+    CHECK(strcmp(out, "71503") == 0);
+
+    CHECK(solve(26, 1, "", out, sizeof out) == AOC2023_STATUS_UNKNOWN_DAY);
+    CHECK(strcmp(last_error(), "day 26 is not solved") == 0);
+
+    CHECK(solve(9, 3, "1 2 3", out, sizeof out) ==
+          AOC2023_STATUS_INVALID_ARGUMENT);
+
+    CHECK(solve(9, 1, "1 2\n3 x", out, sizeof out) ==
+          AOC2023_STATUS_PARSE_ERROR);
+    CHECK(strcmp(last_error(),
+                 "day 9, line 2, column 3: expected a number") == 0);
+
+    CHECK(solve(9, 1, "1 2 3", out, 1) == AOC2023_STATUS_BUFFER_TOO_SMALL);
+    CHECK(aoc2023_last_error(NULL, 0) == strlen(last_error()));
+
+    CHECK(aoc2023_solve(9, 1, NULL, 0, (uint8_t *)out, sizeof out) ==
+          AOC2023_STATUS_PARSE_ERROR);
+    CHECK(aoc2023_solve(9, 1, NULL, 5, (uint8_t *)out, sizeof out) ==
+          AOC2023_STATUS_INVALID_ARGUMENT);
+
+    if (failures) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+    return 0;
+}
@@ -0,0 +1,82 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// Exercises the C API from C: checks the committed header is what cbindgen
+// generates now, then builds `tests/c/ffi_test.c` against the shared library
+// cargo built next to this test and runs it.
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn generate() -> String {
+    let config = cbindgen::Config::from_file(root().join("cbindgen.toml")).unwrap();
+    let mut header = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(root().join("src/ffi.rs"))
+        .generate()
+        .expect("generating the header")
+        .write(&mut header);
+    String::from_utf8(header).unwrap()
+}
+
+// Set `UPDATE_HEADER=1` to rewrite `include/aoc2023.h` instead of failing.
+#[test]
+fn header_is_current() {
+    let path = root().join("include/aoc2023.h");
+    let generated = generate();
+
+    if env::var_os("UPDATE_HEADER").is_some() {
+        fs::write(&path, &generated).unwrap();
+    }
+    let committed = fs::read_to_string(&path).unwrap_or_default();
+
+    assert!(
+        committed == generated,
+        "include/aoc2023.h is out of date, rerun with UPDATE_HEADER=1"
+    );
+}
+
+// The library is built into the same `deps` directory as this test.
+fn library_dir() -> PathBuf {
+    env::current_exe().unwrap().parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn c_program() {
+    let lib_dir = library_dir();
+    let exe = lib_dir.join(format!("ffi_test-{}", std::process::id()));
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    let status = Command::new(&cc)
+        .arg(root().join("tests/c/ffi_test.c"))
+        .arg("-I")
+        .arg(root().join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-ladventofcode")
+        .arg("-o")
+        .arg(&exe)
+        .status()
+        .unwrap_or_else(|e| panic!("running {cc}: {e}"));
+    assert!(status.success(), "compiling tests/c/ffi_test.c");
+
+    // Cargo's own `LD_LIBRARY_PATH` would win over the rpath, and can find a
+    // stale copy of the library in `target/debug`.
+    let output = Command::new(&exe)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .output()
+        .unwrap();
+    fs::remove_file(&exe).unwrap();
+
+    assert!(
+        output.status.success(),
+        "ffi_test failed:\n{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}